@@ -0,0 +1,13 @@
+//! Feeds arbitrary bytes into [`parse_eip191`], which must never panic. Whatever it accepts must
+//! frame back to the same bytes.
+
+#![no_main]
+
+use core_reth_primitives::{eip191_message, parse_eip191};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(message) = parse_eip191(data) {
+        assert_eq!(eip191_message(message), data);
+    }
+});
@@ -0,0 +1,12 @@
+//! Frames arbitrary messages with [`eip191_message`] and checks that [`parse_eip191`] recovers
+//! them.
+
+#![no_main]
+
+use core_reth_primitives::{eip191_message, parse_eip191};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|message: &[u8]| {
+    let framed = eip191_message(message);
+    assert_eq!(parse_eip191(&framed), Ok(message));
+});
@@ -0,0 +1,36 @@
+use crate::{keccak256, sha3};
+use alloy_primitives::B256;
+
+/// A 256-bit hash function selectable at runtime, e.g. from a protocol version field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum HashAlgo {
+    /// SHA3-256, see [`sha3`]. Used throughout Core.
+    #[default]
+    Sha3_256,
+    /// Legacy Keccak-256, see [`keccak256`].
+    Keccak256,
+}
+
+impl HashAlgo {
+    /// Hashes `input` with the selected function.
+    #[inline]
+    pub fn hash(&self, input: &[u8]) -> B256 {
+        match self {
+            Self::Sha3_256 => sha3(input),
+            Self::Keccak256 => keccak256(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch() {
+        assert_eq!(HashAlgo::Sha3_256.hash(b"hello"), sha3(b"hello"));
+        assert_eq!(HashAlgo::Keccak256.hash(b"hello"), keccak256(b"hello"));
+        assert_ne!(HashAlgo::Sha3_256.hash(b"hello"), HashAlgo::Keccak256.hash(b"hello"));
+        assert_eq!(HashAlgo::default(), HashAlgo::Sha3_256);
+    }
+}
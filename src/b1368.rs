@@ -0,0 +1,296 @@
+use crate::B1368;
+use alloc::{string::String, vec::Vec};
+use alloy_primitives::{hex, Bytes};
+use core::{fmt, ops::Range};
+
+/// Error returned when a byte container does not have the length of the target type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LengthError {
+    /// The expected length in bytes.
+    pub expected: usize,
+    /// The actual length in bytes.
+    pub got: usize,
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid length: expected {} bytes, got {}", self.expected, self.got)
+    }
+}
+
+impl core::error::Error for LengthError {}
+
+/// Copies `b` into a new `Vec<u8>`.
+#[inline]
+pub fn b1368_to_vec(b: &B1368) -> Vec<u8> {
+    b.as_slice().to_vec()
+}
+
+/// Converts `b` into a [`B1368`], failing if it is not exactly 1368 bytes long.
+#[inline]
+pub fn b1368_from_bytes(b: &Bytes) -> Result<B1368, LengthError> {
+    b1368_from_slice(b)
+}
+
+/// Converts `b` into a [`B1368`], failing if it is not exactly 1368 bytes long.
+#[inline]
+pub fn b1368_from_slice(b: &[u8]) -> Result<B1368, LengthError> {
+    B1368::try_from(b).map_err(|_| LengthError { expected: B1368::len_bytes(), got: b.len() })
+}
+
+/// Returns the bytes of `b` in `range`, or `None` if the range is out of bounds.
+///
+/// The layout of a [`B1368`] is not fixed by this crate, so sub-fields are addressed by explicit
+/// byte ranges. Unlike indexing, this never panics.
+#[inline]
+pub fn b1368_slice(b: &B1368, range: Range<usize>) -> Option<&[u8]> {
+    b.get(range)
+}
+
+/// Returns the bytes of `b` in `range` mutably, or `None` if the range is out of bounds.
+#[inline]
+pub fn b1368_slice_mut(b: &mut B1368, range: Range<usize>) -> Option<&mut [u8]> {
+    b.get_mut(range)
+}
+
+/// Formats `b` as `0x`-prefixed hex, with a line break after every `chunk` bytes.
+///
+/// The output is meant for human inspection, e.g. in logs and config files, and is parsed back by
+/// [`b1368_from_hex`].
+///
+/// Panics if `chunk` is 0.
+#[track_caller]
+pub fn b1368_to_hex_chunked(b: &B1368, chunk: usize) -> String {
+    assert!(chunk != 0, "chunk size must be non-zero");
+    let lines = b.len().div_ceil(chunk);
+    let mut out = String::with_capacity(2 + b.len() * 2 + lines - 1);
+    out.push_str("0x");
+    for (i, line) in b.chunks(chunk).enumerate() {
+        if i != 0 {
+            out.push('\n');
+        }
+        out.push_str(&hex::encode(line));
+    }
+    out
+}
+
+/// Parses a [`B1368`] from hex, ignoring any whitespace, including line breaks.
+///
+/// The input may be `0x`-prefixed and must encode exactly 1368 bytes. This accepts the output of
+/// [`b1368_to_hex_chunked`] for any chunk size.
+pub fn b1368_from_hex(s: &str) -> Result<B1368, hex::FromHexError> {
+    let compact: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut b = B1368::ZERO;
+    hex::decode_to_slice(compact, &mut b.0)?;
+    Ok(b)
+}
+
+/// Number of `u64` limbs in a [`B1368`]: `1368 / 8 = 171`.
+pub const B1368_LIMBS: usize = 171;
+
+/// Views `b` as 171 `u64` limbs, each decoded from 8 consecutive bytes in big-endian order.
+///
+/// `limbs[0]` holds bytes `0..8`, `limbs[170]` holds bytes `1360..1368`.
+pub fn b1368_as_u64_limbs_be(b: &B1368) -> [u64; B1368_LIMBS] {
+    let mut limbs = [0u64; B1368_LIMBS];
+    for (limb, chunk) in limbs.iter_mut().zip(b.chunks_exact(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    limbs
+}
+
+/// Views `b` as 171 `u64` limbs, each decoded from 8 consecutive bytes in little-endian order.
+///
+/// The limb order is the same as for [`b1368_as_u64_limbs_be`]; only the byte order within each
+/// limb differs.
+pub fn b1368_as_u64_limbs_le(b: &B1368) -> [u64; B1368_LIMBS] {
+    let mut limbs = [0u64; B1368_LIMBS];
+    for (limb, chunk) in limbs.iter_mut().zip(b.chunks_exact(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    limbs
+}
+
+/// Reconstructs a [`B1368`] from the limbs returned by [`b1368_as_u64_limbs_be`].
+pub fn b1368_from_u64_limbs_be(limbs: &[u64; B1368_LIMBS]) -> B1368 {
+    let mut b = B1368::ZERO;
+    for (chunk, limb) in b.chunks_exact_mut(8).zip(limbs) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    b
+}
+
+/// Reconstructs a [`B1368`] from the limbs returned by [`b1368_as_u64_limbs_le`].
+pub fn b1368_from_u64_limbs_le(limbs: &[u64; B1368_LIMBS]) -> B1368 {
+    let mut b = B1368::ZERO;
+    for (chunk, limb) in b.chunks_exact_mut(8).zip(limbs) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    b
+}
+
+/// Number of bits in a [`B1368`]: `1368 * 8 = 10944`.
+pub const B1368_BITS: usize = 1368 * 8;
+
+/// Returns the bitwise AND of `a` and `b`.
+#[inline]
+pub fn b1368_and(a: &B1368, b: &B1368) -> B1368 {
+    *a & *b
+}
+
+/// Returns the bitwise OR of `a` and `b`.
+#[inline]
+pub fn b1368_or(a: &B1368, b: &B1368) -> B1368 {
+    *a | *b
+}
+
+/// Returns the bitwise XOR of `a` and `b`.
+#[inline]
+pub fn b1368_xor(a: &B1368, b: &B1368) -> B1368 {
+    *a ^ *b
+}
+
+/// Returns the bitwise NOT of `b`.
+#[inline]
+pub fn b1368_not(b: &B1368) -> B1368 {
+    !*b
+}
+
+/// Returns bit `i` of `b`, treating it as a bitmap of [`B1368_BITS`] bits.
+///
+/// Bit `i` is bit `i % 8` of byte `i / 8`, counting from the least significant bit, so bits `0..8`
+/// are the bits of byte 0 from `0x01` to `0x80`.
+///
+/// Panics if `i` is not less than [`B1368_BITS`].
+#[inline]
+#[track_caller]
+pub fn b1368_get_bit(b: &B1368, i: usize) -> bool {
+    assert!(i < B1368_BITS, "bit index {i} out of range for {B1368_BITS} bits");
+    b[i / 8] & (1 << (i % 8)) != 0
+}
+
+/// Sets bit `i` of `b` to `val`, with the bit order of [`b1368_get_bit`].
+///
+/// Panics if `i` is not less than [`B1368_BITS`].
+#[inline]
+#[track_caller]
+pub fn b1368_set_bit(b: &mut B1368, i: usize, val: bool) {
+    assert!(i < B1368_BITS, "bit index {i} out of range for {B1368_BITS} bits");
+    let mask = 1 << (i % 8);
+    if val {
+        b[i / 8] |= mask;
+    } else {
+        b[i / 8] &= !mask;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec};
+
+    fn populated() -> B1368 {
+        let mut b = B1368::ZERO;
+        for (i, byte) in b.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        b
+    }
+
+    #[test]
+    fn roundtrip() {
+        let b = populated();
+        let vec = b1368_to_vec(&b);
+        assert_eq!(vec.len(), 1368);
+        assert_eq!(b1368_from_bytes(&Bytes::from(vec.clone())), Ok(b));
+        assert_eq!(b1368_from_slice(&vec), Ok(b));
+    }
+
+    #[test]
+    fn slice_range_checked() {
+        let mut b = populated();
+        assert_eq!(b1368_slice(&b, 0..4), Some(&[0u8, 1, 2, 3][..]));
+        assert_eq!(b1368_slice(&b, 1364..1368).map(<[u8]>::len), Some(4));
+        assert_eq!(b1368_slice(&b, 1364..1369), None);
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 5..4;
+        assert_eq!(b1368_slice(&b, reversed), None);
+
+        b1368_slice_mut(&mut b, 8..10).unwrap().copy_from_slice(&[0xff, 0xee]);
+        assert_eq!(b[8..10], [0xff, 0xee]);
+        assert!(b1368_slice_mut(&mut b, 2000..2001).is_none());
+    }
+
+    #[test]
+    fn hex_chunked_roundtrip() {
+        let b = populated();
+        for chunk in [1, 32, 100, 1368, 2000] {
+            let hex = b1368_to_hex_chunked(&b, chunk);
+            assert_eq!(hex.lines().count(), 1368usize.div_ceil(chunk));
+            assert_eq!(b1368_from_hex(&hex), Ok(b));
+        }
+
+        let hex = b1368_to_hex_chunked(&b, 32);
+        let first_line = "0x000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f\n";
+        assert!(hex.starts_with(first_line));
+        assert_eq!(b1368_from_hex(&hex.replace('\n', "  \r\n\t")), Ok(b));
+        assert_eq!(b1368_from_hex(&hex[2..]), Ok(b));
+        assert!(b1368_from_hex(&hex[..hex.len() - 2]).is_err());
+        assert!(b1368_from_hex(&hex.replace('a', "z")).is_err());
+    }
+
+    #[test]
+    fn limbs_roundtrip() {
+        let b = populated();
+
+        let be = b1368_as_u64_limbs_be(&b);
+        assert_eq!(be[0], 0x0001020304050607);
+        assert_eq!(b1368_from_u64_limbs_be(&be), b);
+
+        let le = b1368_as_u64_limbs_le(&b);
+        assert_eq!(le[0], 0x0706050403020100);
+        assert_eq!(b1368_from_u64_limbs_le(&le), b);
+    }
+
+    #[test]
+    fn bitwise_ops() {
+        let a = populated();
+        let mut b = B1368::ZERO;
+        for i in (0..B1368_BITS).step_by(3) {
+            b1368_set_bit(&mut b, i, true);
+        }
+        b1368_set_bit(&mut b, 3, false);
+        b1368_set_bit(&mut b, B1368_BITS - 1, true);
+
+        let (va, vb) = (b1368_to_vec(&a), b1368_to_vec(&b));
+        for i in [0, 1, 3, 6, 7, 8, 5000, B1368_BITS - 1] {
+            assert_eq!(b1368_get_bit(&b, i), (vb[i / 8] >> (i % 8)) & 1 == 1, "bit {i}");
+        }
+        assert!(b1368_get_bit(&b, 0) && !b1368_get_bit(&b, 1) && !b1368_get_bit(&b, 3));
+        assert_eq!(b[0], 0b0100_0001);
+
+        let zip =
+            |f: fn(u8, u8) -> u8| va.iter().zip(&vb).map(|(x, y)| f(*x, *y)).collect::<Vec<_>>();
+        assert_eq!(b1368_to_vec(&b1368_and(&a, &b)), zip(|x, y| x & y));
+        assert_eq!(b1368_to_vec(&b1368_or(&a, &b)), zip(|x, y| x | y));
+        assert_eq!(b1368_to_vec(&b1368_xor(&a, &b)), zip(|x, y| x ^ y));
+        assert_eq!(b1368_to_vec(&b1368_not(&a)), va.iter().map(|x| !x).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic = "bit index 10944 out of range"]
+    fn bit_out_of_range() {
+        b1368_get_bit(&B1368::ZERO, B1368_BITS);
+    }
+
+    #[test]
+    fn invalid_length() {
+        let bytes = Bytes::from(vec![0u8; 1367]);
+        assert_eq!(b1368_from_bytes(&bytes), Err(LengthError { expected: 1368, got: 1367 }));
+        assert_eq!(b1368_from_slice(&[0u8; 1369]), Err(LengthError { expected: 1368, got: 1369 }));
+        assert_eq!(
+            LengthError { expected: 1368, got: 0 }.to_string(),
+            "invalid length: expected 1368 bytes, got 0"
+        );
+    }
+}
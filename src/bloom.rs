@@ -0,0 +1,133 @@
+use crate::sha3;
+use alloc::{vec, vec::Vec};
+
+/// Maximum number of bit positions per item: one per 32-bit word of a SHA3-256 digest.
+pub const BLOOM_MAX_HASHES: u32 = 8;
+
+/// A bloom filter whose bit positions are derived from the SHA3-256 digest of each item.
+///
+/// The digest is split into eight big-endian 32-bit words, and the first `k` of them, reduced
+/// modulo the number of bits, are the item's positions. The filter is fully determined by its
+/// parameters and the inserted items, so filters built on different nodes are bit-for-bit equal.
+///
+/// With `m` bits, `k` positions per item and `n` inserted items, the false-positive rate of
+/// [`contains`](Self::contains) is approximately `(1 - e^(-k * n / m))^k`. For a given `m / n` it
+/// is minimized by `k = (m / n) * ln 2`; e.g. 10 bits per item with `k = 7` gives about 0.8%.
+/// False negatives are impossible.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bloom {
+    words: Vec<u64>,
+    bits: u32,
+    hashes: u32,
+}
+
+impl Bloom {
+    /// Creates an empty filter of `bits` bits that sets `hashes` bits per item.
+    ///
+    /// Panics if `bits` is 0, or if `hashes` is 0 or greater than [`BLOOM_MAX_HASHES`].
+    #[track_caller]
+    pub fn new(bits: u32, hashes: u32) -> Self {
+        assert!(bits != 0, "bloom filter must have at least one bit");
+        assert!(
+            (1..=BLOOM_MAX_HASHES).contains(&hashes),
+            "bloom filter hash count {hashes} is not in 1..={BLOOM_MAX_HASHES}"
+        );
+        Self { words: vec![0; bits.div_ceil(64) as usize], bits, hashes }
+    }
+
+    /// Returns the number of bits in the filter.
+    #[inline]
+    pub const fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Returns the number of bits set per item.
+    #[inline]
+    pub const fn hashes(&self) -> u32 {
+        self.hashes
+    }
+
+    /// Adds `item` to the filter.
+    pub fn insert(&mut self, item: &[u8]) {
+        for index in self.indices(item) {
+            self.words[index / 64] |= 1 << (index % 64);
+        }
+    }
+
+    /// Returns `true` if `item` may have been inserted, and `false` if it definitely was not.
+    pub fn contains(&self, item: &[u8]) -> bool {
+        self.indices(item).all(|index| self.words[index / 64] & (1 << (index % 64)) != 0)
+    }
+
+    /// Removes all items from the filter.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    fn indices(&self, item: &[u8]) -> impl Iterator<Item = usize> {
+        let digest = sha3(item);
+        let bits = self.bits;
+        (0..self.hashes as usize).map(move |i| {
+            let word = u32::from_be_bytes(digest[i * 4..i * 4 + 4].try_into().unwrap());
+            (word % bits) as usize
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn membership() {
+        let mut bloom = Bloom::new(1024, 7);
+        for i in 0..50 {
+            bloom.insert(format!("item-{i}").as_bytes());
+        }
+        for i in 0..50 {
+            assert!(bloom.contains(format!("item-{i}").as_bytes()));
+        }
+
+        let mut other = Bloom::new(1024, 7);
+        for i in (0..50).rev() {
+            other.insert(format!("item-{i}").as_bytes());
+        }
+        assert_eq!(bloom, other);
+
+        bloom.clear();
+        assert!(!bloom.contains(b"item-0"));
+    }
+
+    #[test]
+    fn positions_from_digest_words() {
+        let mut bloom = Bloom::new(64 * 8, 2);
+        bloom.insert(b"hello");
+        let digest = sha3(b"hello");
+        let mut expected = Bloom::new(64 * 8, 2);
+        for i in 0..2 {
+            let word = u32::from_be_bytes(digest[i * 4..i * 4 + 4].try_into().unwrap());
+            let index = (word % 512) as usize;
+            expected.words[index / 64] |= 1 << (index % 64);
+        }
+        assert_eq!(bloom, expected);
+    }
+
+    #[test]
+    fn false_positive_rate() {
+        // 10 bits per item with k = 7: the expected rate is about 0.8%.
+        let mut bloom = Bloom::new(10_000, 7);
+        for i in 0..1000 {
+            bloom.insert(format!("member-{i}").as_bytes());
+        }
+        let false_positives =
+            (0..10_000).filter(|i| bloom.contains(format!("other-{i}").as_bytes())).count();
+        assert!(false_positives < 300, "{false_positives} false positives");
+    }
+
+    #[test]
+    #[should_panic = "hash count 9"]
+    fn too_many_hashes() {
+        Bloom::new(64, 9);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{recover_address_from_prehash, Signature, SignatureError, SIGNATURE_LENGTH};
+use alloy_primitives::{Address, B256};
+use lru::LruCache;
+use std::{
+    num::NonZeroUsize,
+    sync::{Mutex, PoisonError},
+};
+
+type CacheKey = (B256, [u8; SIGNATURE_LENGTH]);
+
+/// A bounded, thread-safe cache of recovered signers keyed by `(prehash, signature)`.
+///
+/// Recovering a signer is by far the most expensive part of verification. When the same signed
+/// message arrives from many peers, sharing one cache lets all but the first verification skip it.
+/// The least recently used entries are evicted once the cache is full. Failed recoveries are not
+/// cached.
+#[derive(Debug)]
+pub struct VerificationCache {
+    inner: Mutex<LruCache<CacheKey, Address>>,
+}
+
+impl VerificationCache {
+    /// Creates a new cache holding at most `capacity` entries.
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self { inner: Mutex::new(LruCache::new(capacity)) }
+    }
+
+    /// Same as [`recover_address_from_prehash`], but consults the cache first.
+    pub fn recover(&self, prehash: &B256, sig: &Signature) -> Result<Address, SignatureError> {
+        self.recover_with(prehash, sig, recover_address_from_prehash)
+    }
+
+    fn recover_with(
+        &self,
+        prehash: &B256,
+        sig: &Signature,
+        recover: impl FnOnce(&B256, &Signature) -> Result<Address, SignatureError>,
+    ) -> Result<Address, SignatureError> {
+        let key = (*prehash, sig.as_bytes());
+        if let Some(signer) = self.lock().get(&key) {
+            return Ok(*signer);
+        }
+        // Recover without holding the lock so other threads are not blocked on it.
+        let signer = recover(prehash, sig)?;
+        self.lock().put(key, signer);
+        Ok(signer)
+    }
+
+    /// Same as [`verify_eip191`](crate::verify_eip191), but recovers the signer through the cache.
+    pub fn verify_eip191(
+        &self,
+        msg: &[u8],
+        sig: &Signature,
+        expected: Address,
+    ) -> Result<bool, SignatureError> {
+        self.recover(&crate::eip191_hash_message(msg), sig).map(|signer| signer == expected)
+    }
+
+    /// Returns the number of cached entries.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Removes all cached entries.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruCache<CacheKey, Address>> {
+        // The cache is never left in an inconsistent state, so a poisoned lock is still usable.
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        eip191_hash_message,
+        signature::fixtures::{sig, CORE_SIG, MESSAGE, SIGNER},
+    };
+    use core::cell::Cell;
+
+    #[test]
+    fn matches_cold_recovery() {
+        let cache = VerificationCache::new(NonZeroUsize::new(2).unwrap());
+        let sig = sig(CORE_SIG);
+        let prehash = eip191_hash_message(MESSAGE);
+
+        let cold = recover_address_from_prehash(&prehash, &sig).unwrap();
+        assert_eq!(cold, SIGNER);
+        assert_eq!(cache.recover(&prehash, &sig).unwrap(), cold);
+        assert_eq!(cache.len(), 1);
+        // Served from the cache: recovering again would give a different signer.
+        let hit = cache.recover_with(&prehash, &sig, |_, _| Ok(Address::ZERO));
+        assert_eq!(hit.unwrap(), cold);
+        assert!(cache.verify_eip191(MESSAGE, &sig, SIGNER).unwrap());
+        assert_eq!(cache.len(), 1);
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = VerificationCache::new(NonZeroUsize::new(2).unwrap());
+        let sig = sig(CORE_SIG);
+        let calls = Cell::new(0);
+        let recover = |prehash: &B256, _: &Signature| {
+            calls.set(calls.get() + 1);
+            Ok(Address::from_word(*prehash))
+        };
+        let [a, b, c] = [B256::repeat_byte(1), B256::repeat_byte(2), B256::repeat_byte(3)];
+
+        for (prehash, len, total_calls) in [(a, 1, 1), (b, 2, 2), (a, 2, 2), (c, 2, 3)] {
+            let signer = cache.recover_with(&prehash, &sig, recover).unwrap();
+            assert_eq!(signer, Address::from_word(prehash));
+            assert_eq!(cache.len(), len);
+            assert_eq!(calls.get(), total_calls);
+        }
+
+        // `b` was the least recently used entry when `c` was inserted.
+        cache.recover_with(&a, &sig, recover).unwrap();
+        assert_eq!(calls.get(), 3);
+        cache.recover_with(&b, &sig, recover).unwrap();
+        assert_eq!(calls.get(), 4);
+
+        // Failures are not cached.
+        let failed =
+            cache.recover_with(&B256::ZERO, &sig, |_, _| Err(SignatureError::FromBytes("invalid")));
+        assert!(failed.is_err());
+        assert_eq!(cache.len(), 2);
+    }
+}
@@ -0,0 +1,107 @@
+//! C ABI for embedding [`Sha3`] in non-Rust hosts.
+//!
+//! A hasher is created with [`core_sha3_new`], fed with [`core_sha3_update`] and consumed by
+//! [`core_sha3_finalize`]. [`core_sha3_free`] releases a hasher that is not finalized. The
+//! corresponding C declarations are:
+//!
+//! ```c
+//! typedef struct CoreSha3 CoreSha3;
+//!
+//! CoreSha3 *core_sha3_new(void);
+//! void core_sha3_update(CoreSha3 *h, const uint8_t *ptr, size_t len);
+//! void core_sha3_finalize(CoreSha3 *h, uint8_t out32[32]);
+//! void core_sha3_free(CoreSha3 *h);
+//! void core_sha3_oneshot(const uint8_t *ptr, size_t len, uint8_t out32[32]);
+//! ```
+
+use crate::{sha3, Sha3};
+use alloc::boxed::Box;
+
+/// Allocates a new hasher. The result is never null.
+///
+/// The hasher must be released with either [`core_sha3_finalize`] or [`core_sha3_free`].
+#[no_mangle]
+pub extern "C" fn core_sha3_new() -> *mut Sha3 {
+    Box::into_raw(Box::new(Sha3::new()))
+}
+
+/// Absorbs `len` bytes at `ptr` into `h`.
+///
+/// # Safety
+///
+/// `h` must be a live hasher returned by [`core_sha3_new`]. `ptr` must be valid for reads of `len`
+/// bytes; it may be null only if `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn core_sha3_update(h: *mut Sha3, ptr: *const u8, len: usize) {
+    if len == 0 {
+        return;
+    }
+    (*h).update(core::slice::from_raw_parts(ptr, len));
+}
+
+/// Writes the 32-byte digest of `h` to `out32` and frees `h`.
+///
+/// # Safety
+///
+/// `h` must be a live hasher returned by [`core_sha3_new`], and must not be used afterwards.
+/// `out32` must be valid for writes of 32 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn core_sha3_finalize(h: *mut Sha3, out32: *mut u8) {
+    Box::from_raw(h).finalize_into_raw(out32);
+}
+
+/// Frees `h` without finalizing it. Does nothing if `h` is null.
+///
+/// # Safety
+///
+/// `h` must be null or a live hasher returned by [`core_sha3_new`], and must not be used
+/// afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn core_sha3_free(h: *mut Sha3) {
+    if !h.is_null() {
+        drop(Box::from_raw(h));
+    }
+}
+
+/// Writes the 32-byte digest of the `len` bytes at `ptr` to `out32`.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reads of `len` bytes; it may be null only if `len` is 0. `out32` must be
+/// valid for writes of 32 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn core_sha3_oneshot(ptr: *const u8, len: usize, out32: *mut u8) {
+    let input = if len == 0 { &[][..] } else { core::slice::from_raw_parts(ptr, len) };
+    out32.copy_from_nonoverlapping(sha3(input).as_ptr(), 32);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ptr;
+
+    #[test]
+    fn c_api_matches_sha3() {
+        let input = b"hello world";
+        let mut out = [0u8; 32];
+        unsafe {
+            let h = core_sha3_new();
+            core_sha3_update(h, input.as_ptr(), 5);
+            core_sha3_update(h, ptr::null(), 0);
+            core_sha3_update(h, input[5..].as_ptr(), 6);
+            core_sha3_finalize(h, out.as_mut_ptr());
+        }
+        assert_eq!(out, sha3(input));
+
+        let mut out = [0u8; 32];
+        unsafe { core_sha3_oneshot(input.as_ptr(), input.len(), out.as_mut_ptr()) };
+        assert_eq!(out, sha3(input));
+        unsafe { core_sha3_oneshot(ptr::null(), 0, out.as_mut_ptr()) };
+        assert_eq!(out, sha3(b""));
+
+        unsafe {
+            core_sha3_free(core_sha3_new());
+            core_sha3_free(ptr::null_mut());
+        }
+    }
+}
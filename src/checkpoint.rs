@@ -0,0 +1,112 @@
+use crate::Sha3;
+use alloc::vec::Vec;
+use alloy_primitives::B256;
+
+/// A [`Sha3`] hasher that records the digest of the input absorbed so far at fixed byte offsets.
+///
+/// The checkpoint at offset `o` is the SHA3-256 digest of the first `o` bytes, as returned by
+/// [`Sha3::peek`] at that point. A partially downloaded file can be checked against a manifest of
+/// checkpoints without hashing it again from the start, and the hasher can be kept to resume.
+///
+/// Checkpoints do not depend on how the input is split across [`update`](Self::update) calls.
+#[derive(Clone, Debug)]
+pub struct CheckpointHasher {
+    hasher: Sha3,
+    position: u64,
+    offsets: Vec<u64>,
+    checkpoints: Vec<(u64, B256)>,
+}
+
+impl CheckpointHasher {
+    /// Creates a hasher that records a checkpoint at each of `offsets`.
+    ///
+    /// Panics if `offsets` is not strictly ascending.
+    #[track_caller]
+    pub fn new(offsets: impl IntoIterator<Item = u64>) -> Self {
+        let offsets: Vec<u64> = offsets.into_iter().collect();
+        assert!(offsets.windows(2).all(|w| w[0] < w[1]), "offsets must be strictly ascending");
+        let mut hasher = Self {
+            hasher: Sha3::new(),
+            position: 0,
+            checkpoints: Vec::with_capacity(offsets.len()),
+            offsets,
+        };
+        // Records a checkpoint at offset 0.
+        hasher.update([]);
+        hasher
+    }
+
+    /// Absorbs additional input, recording the checkpoints it crosses.
+    pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        let mut bytes = bytes.as_ref();
+        while let Some(&offset) = self.offsets.get(self.checkpoints.len()) {
+            let take = usize::try_from(offset - self.position)
+                .map_or(bytes.len(), |missing| missing.min(bytes.len()));
+            self.hasher.update(&bytes[..take]);
+            self.position += take as u64;
+            bytes = &bytes[take..];
+            if self.position != offset {
+                return;
+            }
+            self.checkpoints.push((offset, self.hasher.peek()));
+        }
+        self.hasher.update(bytes);
+        self.position += bytes.len() as u64;
+    }
+
+    /// Returns the number of bytes absorbed so far.
+    #[inline]
+    pub const fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the checkpoints recorded so far as `(offset, digest)` pairs, in ascending order.
+    #[inline]
+    pub fn checkpoints(&self) -> &[(u64, B256)] {
+        &self.checkpoints
+    }
+
+    /// Pad and squeeze the state, returning the digest of all absorbed input.
+    #[inline]
+    #[must_use]
+    pub fn finalize(self) -> B256 {
+        self.hasher.finalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha3;
+
+    #[test]
+    fn checkpoints_at_offsets() {
+        let data: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        let offsets = [0, 1, 136, 137, 500, 999, 1000, 2000];
+
+        for chunk_sizes in [&[1000][..], &[1], &[7, 129, 3], &[136, 1, 0, 400]] {
+            let mut hasher = CheckpointHasher::new(offsets);
+            let mut rest = &data[..];
+            for &size in chunk_sizes.iter().cycle() {
+                if rest.is_empty() {
+                    break;
+                }
+                let (chunk, tail) = rest.split_at(size.min(rest.len()));
+                hasher.update(chunk);
+                rest = tail;
+            }
+
+            let expected: Vec<(u64, B256)> =
+                offsets[..7].iter().map(|&o| (o, sha3(&data[..o as usize]))).collect();
+            assert_eq!(hasher.checkpoints(), expected);
+            assert_eq!(hasher.position(), 1000);
+            assert_eq!(hasher.finalize(), sha3(&data));
+        }
+    }
+
+    #[test]
+    #[should_panic = "strictly ascending"]
+    fn unordered_offsets() {
+        CheckpointHasher::new([10, 10]);
+    }
+}
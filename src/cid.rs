@@ -0,0 +1,194 @@
+use crate::sha3;
+use alloy_primitives::B256;
+use core::{fmt, str::FromStr};
+
+/// The lowercase RFC 4648 base32 alphabet.
+const ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// Length of the base32 encoding of a 32-byte digest, without padding.
+const ENCODED_LEN: usize = 52;
+
+/// A content-addressed identifier: the SHA3-256 digest of some content.
+///
+/// The textual form is `cid_` followed by the digest in unpadded, lowercase RFC 4648 base32,
+/// 56 characters in total. This is URL-safe and shorter than hex. Two different contents have the
+/// same ID only if they collide under SHA3-256, which is computationally infeasible.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ContentId(B256);
+
+impl ContentId {
+    /// The prefix of the textual form.
+    pub const PREFIX: &'static str = "cid_";
+
+    /// Computes the ID of `bytes`.
+    #[inline]
+    pub fn of(bytes: &[u8]) -> Self {
+        Self(sha3(bytes))
+    }
+
+    /// Wraps an existing `digest`.
+    #[inline]
+    pub const fn from_digest(digest: B256) -> Self {
+        Self(digest)
+    }
+
+    /// Returns the digest.
+    #[inline]
+    pub const fn digest(&self) -> &B256 {
+        &self.0
+    }
+}
+
+impl fmt::Display for ContentId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = [0u8; ENCODED_LEN];
+        let (mut acc, mut bits, mut n) = (0u16, 0, 0);
+        for &byte in self.0.iter() {
+            acc = (acc << 8) | u16::from(byte);
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                out[n] = ALPHABET[usize::from((acc >> bits) & 0x1f)];
+                n += 1;
+            }
+        }
+        // 256 bits leave 1 bit, padded with zeros to a full character.
+        out[n] = ALPHABET[usize::from((acc << (5 - bits)) & 0x1f)];
+
+        f.write_str(Self::PREFIX)?;
+        // SAFETY: the alphabet is ASCII.
+        f.write_str(unsafe { core::str::from_utf8_unchecked(&out) })
+    }
+}
+
+impl FromStr for ContentId {
+    type Err = ParseContentIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let encoded = s.strip_prefix(Self::PREFIX).ok_or(ParseContentIdError::MissingPrefix)?;
+        if encoded.len() != ENCODED_LEN {
+            return Err(ParseContentIdError::InvalidLength);
+        }
+
+        let mut digest = B256::ZERO;
+        let (mut acc, mut bits, mut n) = (0u16, 0, 0);
+        for c in encoded.bytes() {
+            let value = match c {
+                b'a'..=b'z' => c - b'a',
+                b'2'..=b'7' => c - b'2' + 26,
+                _ => return Err(ParseContentIdError::InvalidCharacter(c as char)),
+            };
+            acc = (acc << 5) | u16::from(value);
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                digest[n] = (acc >> bits) as u8;
+                n += 1;
+            }
+        }
+        // Only one encoding of each digest is accepted: the padding bits must be zero.
+        if acc & ((1 << bits) - 1) != 0 {
+            return Err(ParseContentIdError::NonCanonical);
+        }
+        Ok(Self(digest))
+    }
+}
+
+impl From<ContentId> for B256 {
+    #[inline]
+    fn from(id: ContentId) -> Self {
+        id.0
+    }
+}
+
+/// Error returned when parsing a [`ContentId`] fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseContentIdError {
+    /// The input does not start with [`ContentId::PREFIX`].
+    MissingPrefix,
+    /// The encoded digest is not 52 characters long.
+    InvalidLength,
+    /// The encoded digest contains a character outside the lowercase base32 alphabet.
+    InvalidCharacter(char),
+    /// The unused trailing bits of the encoding are not zero.
+    NonCanonical,
+}
+
+impl fmt::Display for ParseContentIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPrefix => {
+                write!(f, "content ID must start with `{}`", ContentId::PREFIX)
+            }
+            Self::InvalidLength => {
+                write!(f, "content ID must have {ENCODED_LEN} base32 characters")
+            }
+            Self::InvalidCharacter(c) => write!(f, "invalid base32 character {c:?} in content ID"),
+            Self::NonCanonical => f.write_str("content ID has non-zero padding bits"),
+        }
+    }
+}
+
+impl core::error::Error for ParseContentIdError {}
+
+/// Derives a version 8 [`Uuid`](uuid::Uuid) from `namespace` and `name`.
+///
+/// This is like a version 5 UUID, but with SHA3-256 instead of SHA-1: the first 16 bytes of
+/// `sha3(namespace || name)` are taken, then the high nibble of byte 6 is set to the version `8`
+/// and the two high bits of byte 8 to the RFC 9562 variant `0b10`. The remaining 122 bits come
+/// from the digest, so the same inputs always yield the same UUID.
+///
+/// `namespace` and `name` are concatenated without a separator, so callers should use a
+/// fixed-length namespace, e.g. a UUID.
+#[cfg(feature = "uuid")]
+pub fn deterministic_uuid(namespace: &[u8], name: &[u8]) -> uuid::Uuid {
+    let digest = crate::sha3_slices(&[namespace, name]);
+    let mut bytes: [u8; 16] = digest[..16].try_into().unwrap();
+    bytes[6] = (bytes[6] & 0x0f) | 0x80;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    uuid::Uuid::from_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn roundtrip() {
+        for bytes in [&b""[..], b"hello world", &[0xff; 100]] {
+            let id = ContentId::of(bytes);
+            let s = id.to_string();
+            assert_eq!(s.len(), 56);
+            assert!(s.starts_with("cid_"));
+            assert_eq!(s.parse(), Ok(id));
+        }
+        let zeros = ContentId::from_digest(B256::ZERO).to_string();
+        assert_eq!(zeros, ["cid_", &"a".repeat(52)].concat());
+        let ones = ContentId::from_digest(B256::repeat_byte(0xff)).to_string();
+        assert_eq!(ones, ["cid_", &"7".repeat(51), "q"].concat());
+    }
+
+    #[test]
+    fn parse_malformed() {
+        let valid = ContentId::of(b"hello world").to_string();
+        assert_eq!(valid[4..].parse::<ContentId>(), Err(ParseContentIdError::MissingPrefix));
+        assert_eq!(valid[..55].parse::<ContentId>(), Err(ParseContentIdError::InvalidLength));
+        let upper = ["cid_", &valid[4..].to_uppercase()].concat();
+        let err = upper.parse::<ContentId>().unwrap_err();
+        assert!(matches!(err, ParseContentIdError::InvalidCharacter(_)));
+        let padded = ["cid_", &"a".repeat(51), "b"].concat();
+        assert_eq!(padded.parse::<ContentId>(), Err(ParseContentIdError::NonCanonical));
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn uuid_v8() {
+        let id = deterministic_uuid(b"core", b"alice");
+        assert_eq!(id, uuid::uuid!("86b31a12-f97a-836c-8787-35478ae8d6f2"));
+        assert_eq!(id, deterministic_uuid(b"core", b"alice"));
+        assert_eq!(id.get_version_num(), 8);
+        assert_eq!(id.get_variant(), uuid::Variant::RFC4122);
+        assert_ne!(id, deterministic_uuid(b"core", b"bob"));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{constants::SHA3_EMPTY, ct_eq, Sha3};
+use alloc::{collections::VecDeque, vec::Vec};
+use alloy_primitives::B256;
+
+/// Commits to `value` under the secret `nonce`: `sha3(value || nonce)`.
+///
+/// The nonce is fixed-size and absorbed last, so the encoding is unambiguous for values of any
+/// length. The nonce must be random and kept secret until the reveal, otherwise low-entropy values
+/// can be recovered from the commitment by brute force.
+pub fn commit(value: &[u8], nonce: &B256) -> B256 {
+    let mut hasher = Sha3::new();
+    hasher.update(value);
+    hasher.update(nonce);
+    hasher.finalize()
+}
+
+/// Verifies that `value` and `nonce` open `commitment`, i.e. that it is their [`commit`].
+///
+/// The comparison runs in constant time.
+pub fn verify_reveal(commitment: B256, value: &[u8], nonce: &B256) -> bool {
+    ct_eq(commit(value, nonce).as_slice(), commitment.as_slice())
+}
+
+/// Returns a fresh 32-byte nonce for [`commit`] from the operating system's random number
+/// generator.
+///
+/// Every commitment needs its own nonce: reusing one lets anyone tell whether two commitments hide
+/// the same value, which breaks hiding.
+///
+/// Panics if the operating system's random number generator fails.
+#[cfg(feature = "getrandom")]
+pub fn random_nonce() -> B256 {
+    let mut nonce = B256::ZERO;
+    getrandom::getrandom(&mut nonce.0).expect("failed to generate a random nonce");
+    nonce
+}
+
+/// A commitment to the most recent `K` items of a stream.
+///
+/// The digest is the SHA3-256 hash of the retained items in push order, each absorbed with
+/// [`Sha3::update_prefixed`] so item boundaries are unambiguous. Before the first push it is the
+/// hash of the empty window, [`SHA3_EMPTY`].
+///
+/// SHA3 cannot remove absorbed input, so every [`push`](Self::push) rehashes the whole window, at
+/// a cost of O(K) items. The items are copied and retained for that purpose.
+#[derive(Clone, Debug)]
+pub struct RollingCommit {
+    window: VecDeque<Vec<u8>>,
+    capacity: usize,
+    digest: B256,
+}
+
+impl RollingCommit {
+    /// Creates an empty commitment over a window of the last `capacity` items.
+    ///
+    /// Panics if `capacity` is 0.
+    #[track_caller]
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity != 0, "window capacity must be non-zero");
+        Self { window: VecDeque::with_capacity(capacity), capacity, digest: SHA3_EMPTY }
+    }
+
+    /// Appends `item`, evicting the oldest item if the window is full, and recomputes the digest.
+    pub fn push(&mut self, item: &[u8]) {
+        if self.window.len() == self.capacity {
+            self.window.pop_front();
+        }
+        self.window.push_back(item.to_vec());
+
+        let mut hasher = Sha3::new();
+        for item in &self.window {
+            hasher.update_prefixed(item);
+        }
+        self.digest = hasher.finalize();
+    }
+
+    /// Returns the commitment to the current window.
+    #[inline]
+    pub const fn digest(&self) -> B256 {
+        self.digest
+    }
+
+    /// Returns the number of items in the window, at most the capacity.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.window.len()
+    }
+
+    /// Returns `true` if nothing was pushed yet.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha3;
+
+    #[test]
+    fn commit_is_value_then_nonce() {
+        let nonce = B256::repeat_byte(0x42);
+        assert_eq!(commit(b"vote", &nonce), sha3([&b"vote"[..], nonce.as_slice()].concat()));
+    }
+
+    #[test]
+    fn reveal() {
+        let nonce = B256::repeat_byte(0x42);
+        let commitment = commit(b"vote", &nonce);
+        assert!(verify_reveal(commitment, b"vote", &nonce));
+        assert!(!verify_reveal(commitment, b"votf", &nonce));
+        assert!(!verify_reveal(commitment, b"", &nonce));
+        assert!(!verify_reveal(commitment, b"vote", &B256::repeat_byte(0x43)));
+    }
+
+    #[test]
+    fn rolling_commit_keeps_last_items() {
+        let mut rolling = RollingCommit::new(3);
+        assert!(rolling.is_empty());
+        assert_eq!(rolling.digest(), SHA3_EMPTY);
+
+        let items: [&[u8]; 5] = [b"a", b"bc", b"", b"def", b"g"];
+        for item in items {
+            rolling.push(item);
+        }
+        assert_eq!(rolling.len(), 3);
+        assert_eq!(rolling.digest(), crate::sha3_tuple!(b"", b"def", b"g"));
+
+        let mut fresh = RollingCommit::new(3);
+        for item in &items[2..] {
+            fresh.push(item);
+        }
+        assert_eq!(rolling.digest(), fresh.digest());
+        fresh.push(b"h");
+        assert_ne!(rolling.digest(), fresh.digest());
+    }
+
+    #[test]
+    #[cfg(feature = "getrandom")]
+    fn random_nonces_differ() {
+        let (a, b) = (random_nonce(), random_nonce());
+        assert_ne!(a, b);
+        assert_ne!(commit(b"vote", &a), commit(b"vote", &b));
+    }
+}
@@ -4,3 +4,26 @@ use alloy_primitives::{b256, B256};
 pub const SHA3_EMPTY: B256 =
     b256!("a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a");
 
+/// Sha3_256 of the bytes of [`EIP191_PREFIX`](crate::EIP191_PREFIX), for auditing the framing.
+///
+/// There is no precomputed prefix-absorbed hasher: the `tiny_keccak` state cannot be built in a
+/// const context, and the 22-byte prefix is shorter than one 136-byte block, so absorbing it only
+/// buffers the bytes and never runs a permutation.
+pub const EIP191_PREFIX_HASH: B256 =
+    b256!("1f684078a76926cfb7e187e928c36ee4697ad786f27aae4c8770af800e416282");
+
+/// Order `n` of the secp256k1 group, big-endian.
+pub const SECP256K1_ORDER: B256 =
+    b256!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sha3, EIP191_PREFIX};
+
+    #[test]
+    fn constants_match_their_definition() {
+        assert_eq!(SHA3_EMPTY, sha3(b""));
+        assert_eq!(EIP191_PREFIX_HASH, sha3(EIP191_PREFIX));
+    }
+}
@@ -0,0 +1,249 @@
+use crate::sha3;
+use alloy_primitives::B256;
+use core::fmt;
+
+/// A SHA3-256 digest.
+///
+/// Wraps a [`B256`] to distinguish digests from arbitrary 32-byte values. Literal digests can be
+/// written with [`sha3_digest!`](crate::sha3_digest).
+///
+/// Digests compare equal to [`B256`]s, byte arrays and byte slices with the same contents, which
+/// keeps assertions short. These comparisons are not constant-time; use [`ct_eq`](crate::ct_eq)
+/// where the digest is secret, e.g. for MAC tags.
+///
+/// Digests are first-class map keys: equality and hashing are those of the 32 bytes, and the order
+/// is byte-wise lexicographic, i.e. the canonical order of [`sort_digests`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Sha3Digest(pub B256);
+
+impl Sha3Digest {
+    /// Wraps `digest`.
+    #[inline]
+    pub const fn new(digest: B256) -> Self {
+        Self(digest)
+    }
+
+    /// Computes the SHA3-256 digest of `bytes`.
+    #[inline]
+    pub fn of(bytes: impl AsRef<[u8]>) -> Self {
+        Self(sha3(bytes))
+    }
+
+    /// Returns the digest as a [`B256`].
+    #[inline]
+    pub const fn as_b256(&self) -> &B256 {
+        &self.0
+    }
+
+    /// Consumes the wrapper and returns the inner [`B256`].
+    #[inline]
+    pub const fn into_inner(self) -> B256 {
+        self.0
+    }
+}
+
+impl From<B256> for Sha3Digest {
+    #[inline]
+    fn from(digest: B256) -> Self {
+        Self(digest)
+    }
+}
+
+impl From<Sha3Digest> for B256 {
+    #[inline]
+    fn from(digest: Sha3Digest) -> Self {
+        digest.0
+    }
+}
+
+impl PartialEq<B256> for Sha3Digest {
+    #[inline]
+    fn eq(&self, other: &B256) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<[u8; 32]> for Sha3Digest {
+    #[inline]
+    fn eq(&self, other: &[u8; 32]) -> bool {
+        self.0 .0 == *other
+    }
+}
+
+impl PartialEq<[u8]> for Sha3Digest {
+    #[inline]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.0.as_slice() == other
+    }
+}
+
+impl PartialEq<&[u8]> for Sha3Digest {
+    #[inline]
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.0.as_slice() == *other
+    }
+}
+
+impl AsRef<[u8]> for Sha3Digest {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl fmt::Display for Sha3Digest {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Parses a 32-byte hex literal into a [`Sha3Digest`] at compile time.
+///
+/// The literal may be `0x`-prefixed. Literals that are not exactly 32 bytes of hex fail to compile:
+///
+/// ```
+/// use core_reth_primitives::{sha3, sha3_digest, Sha3Digest};
+///
+/// const HELLO_WORLD: Sha3Digest =
+///     sha3_digest!("0x644bcc7e564373040999aac89e7622f3ca71fba1d972fd94a31c3bfbf24e3938");
+/// assert_eq!(HELLO_WORLD.into_inner(), sha3("hello world"));
+/// ```
+///
+/// ```compile_fail
+/// // 31 bytes.
+/// let _ = core_reth_primitives::sha3_digest!(
+///     "0x644bcc7e564373040999aac89e7622f3ca71fba1d972fd94a31c3bfbf24e39"
+/// );
+/// ```
+#[macro_export]
+macro_rules! sha3_digest {
+    ($s:literal) => {{
+        const DIGEST: $crate::Sha3Digest =
+            $crate::Sha3Digest::new($crate::__private::alloy_primitives::b256!($s));
+        DIGEST
+    }};
+}
+
+/// XORs all `digests` together, returning [`B256::ZERO`] for an empty slice.
+///
+/// The result is independent of the order of the digests, which makes it usable as a simple
+/// accumulator over a set of hashed elements.
+///
+/// # Security
+///
+/// An XOR accumulator is neither a MAC nor collision resistant in the usual sense: every element
+/// cancels itself out when added twice, and finding a set of digests that XOR to a chosen target is
+/// a linear algebra problem. Only use it when the elements are themselves trusted, e.g. to compare
+/// two sets that were built from the same source.
+pub fn xor_digests(digests: &[B256]) -> B256 {
+    digests.iter().fold(B256::ZERO, |acc, digest| acc ^ *digest)
+}
+
+/// Sorts `digests` into canonical order: ascending by their bytes compared lexicographically,
+/// which is the same as comparing them as big-endian 256-bit integers.
+///
+/// Independent implementations must agree on this order before committing to a set of digests.
+#[inline]
+pub fn sort_digests(digests: &mut [B256]) {
+    digests.sort_unstable();
+}
+
+/// Returns `true` if `digests` are in the canonical order of [`sort_digests`].
+///
+/// Duplicates are allowed, i.e. the order is non-decreasing.
+#[inline]
+pub fn is_sorted_digests(digests: &[B256]) -> bool {
+    digests.is_sorted()
+}
+
+/// Returns the index of the first byte in which `a` and `b` differ, or `None` if they are equal.
+///
+/// This is a debugging aid, e.g. a difference starting at byte 12 of an address-sized value hints
+/// at a truncation or padding bug. It returns as soon as a difference is found, so it is not
+/// constant-time; use [`ct_eq`](crate::ct_eq) to compare secret digests.
+pub fn digest_diff(a: &B256, b: &B256) -> Option<usize> {
+    a.iter().zip(b).position(|(x, y)| x != y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digest_literal() {
+        let digest =
+            sha3_digest!("644bcc7e564373040999aac89e7622f3ca71fba1d972fd94a31c3bfbf24e3938");
+        assert_eq!(digest, Sha3Digest::of("hello world"));
+        assert_eq!(B256::from(digest), sha3("hello world"));
+    }
+
+    #[test]
+    fn compare_with_bytes() {
+        let digest = Sha3Digest::of("hello world");
+        let bytes = sha3("hello world");
+        assert_eq!(digest, bytes);
+        assert_eq!(digest, bytes.0);
+        assert_eq!(digest, bytes.as_slice());
+        assert_eq!(digest, *bytes.as_slice());
+        assert_ne!(digest, B256::ZERO);
+        assert_ne!(digest, &bytes[..31]);
+    }
+
+    #[test]
+    fn map_keys() {
+        use alloc::collections::BTreeMap;
+
+        let a = Sha3Digest::new(B256::with_last_byte(1));
+        let b = Sha3Digest::new(B256::repeat_byte(0xff));
+        let mut map = BTreeMap::new();
+        map.insert(b, "b");
+        map.insert(a, "a");
+        assert_eq!(map.get(&a), Some(&"a"));
+        assert_eq!(map.keys().copied().collect::<alloc::vec::Vec<_>>(), [a, b]);
+        assert!(a < b);
+
+        #[cfg(feature = "std")]
+        {
+            let set: std::collections::HashSet<_> = [a, b, a].into_iter().collect();
+            assert_eq!(set.len(), 2);
+            assert!(set.contains(&Sha3Digest::new(B256::with_last_byte(1))));
+        }
+    }
+
+    #[test]
+    fn canonical_order() {
+        let a = B256::with_last_byte(1);
+        let b = B256::left_padding_from(&[1, 0]);
+        let c = B256::repeat_byte(0xff);
+        let mut digests = [c, a, b, a];
+        assert!(!is_sorted_digests(&digests));
+        sort_digests(&mut digests);
+        assert_eq!(digests, [a, a, b, c]);
+        assert!(is_sorted_digests(&digests));
+        assert!(is_sorted_digests(&[]));
+    }
+
+    #[test]
+    fn xor_is_commutative() {
+        let a = sha3("a");
+        let b = sha3("b");
+        let c = sha3("c");
+        assert_eq!(xor_digests(&[]), B256::ZERO);
+        assert_eq!(xor_digests(&[a]), a);
+        assert_eq!(xor_digests(&[a, b, c]), xor_digests(&[c, a, b]));
+        assert_eq!(xor_digests(&[a, b, a]), b);
+    }
+
+    #[test]
+    fn first_difference() {
+        let a = sha3("hello world");
+        assert_eq!(digest_diff(&a, &a), None);
+
+        let mut b = a;
+        b[7] ^= 0x10;
+        b[20] ^= 0x01;
+        assert_eq!(digest_diff(&a, &b), Some(7));
+        assert_eq!(digest_diff(&B256::ZERO, &B256::with_last_byte(1)), Some(31));
+    }
+}
@@ -0,0 +1,204 @@
+use crate::Sha3;
+use alloy_primitives::{Address, B256};
+
+/// Marker absorbed in front of the chain id.
+const CHAIN_ID_MARKER: u8 = 0x01;
+/// Marker absorbed in front of the contract address.
+const ADDRESS_MARKER: u8 = 0x02;
+
+/// A reusable domain for domain-separated [`Sha3`] hashing.
+///
+/// The domain is absorbed once when the separator is built, and [`hasher`](Self::hasher) hands out
+/// clones of the pre-seeded state, which is cheaper than absorbing the domain again for every item.
+///
+/// The domain is encoded as:
+/// `tag.len() as u64 (big-endian) || tag [|| 0x01 || chain_id (big-endian)] [|| 0x02 || address]`
+///
+/// The layout is fixed: the chain id always comes before the address, regardless of the order of
+/// the [`with_chain_id`](Self::with_chain_id) and [`with_address`](Self::with_address) calls.
+#[derive(Clone, Debug)]
+pub struct DomainSeparator {
+    /// State after absorbing the tag only.
+    tag: Sha3,
+    chain_id: Option<u64>,
+    address: Option<Address>,
+    /// `tag` with the chain id and address absorbed.
+    hasher: Sha3,
+}
+
+impl DomainSeparator {
+    /// Creates a new domain separator from `tag`.
+    pub fn new(tag: impl AsRef<[u8]>) -> Self {
+        let mut hasher = Sha3::new();
+        hasher.update_prefixed(tag);
+        Self { tag: hasher.clone(), chain_id: None, address: None, hasher }
+    }
+
+    /// Binds the domain to `chain_id`, replacing any previously bound chain id.
+    pub fn with_chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self.reseed();
+        self
+    }
+
+    /// Binds the domain to the contract at `address`, replacing any previously bound address.
+    pub fn with_address(mut self, address: Address) -> Self {
+        self.address = Some(address);
+        self.reseed();
+        self
+    }
+
+    /// Rebuilds the seeded state from the tag state, in the fixed layout order.
+    fn reseed(&mut self) {
+        let mut hasher = self.tag.clone();
+        if let Some(chain_id) = self.chain_id {
+            hasher.update([CHAIN_ID_MARKER]);
+            hasher.update(chain_id.to_be_bytes());
+        }
+        if let Some(address) = self.address {
+            hasher.update([ADDRESS_MARKER]);
+            hasher.update(address);
+        }
+        self.hasher = hasher;
+    }
+
+    /// Returns a fresh [`Sha3`] hasher seeded with the domain.
+    #[inline]
+    pub fn hasher(&self) -> Sha3 {
+        self.hasher.clone()
+    }
+
+    /// Hashes `bytes` under the domain.
+    #[inline]
+    pub fn hash(&self, bytes: impl AsRef<[u8]>) -> B256 {
+        let mut hasher = self.hasher();
+        hasher.update(bytes);
+        hasher.finalize()
+    }
+}
+
+/// A compile-time domain tag for [`sha3_domain`].
+///
+/// Implement this with [`domain_tag!`](crate::domain_tag), which caches the [`DomainSeparator`]
+/// for the tag in a static so the tag is only absorbed once per process.
+pub trait DomainTag {
+    /// The domain tag.
+    const TAG: &'static [u8];
+
+    /// Returns the separator for [`TAG`](Self::TAG), i.e. `DomainSeparator::new(Self::TAG)`.
+    fn separator() -> &'static DomainSeparator;
+}
+
+/// Hashes `input` under the static domain `T`.
+///
+/// This starts from the cached post-tag state of `T` and is equivalent to
+/// `DomainSeparator::new(T::TAG).hash(input)`.
+#[inline]
+pub fn sha3_domain<T: DomainTag>(input: impl AsRef<[u8]>) -> B256 {
+    T::separator().hash(input)
+}
+
+/// Defines a unit struct implementing [`DomainTag`] with a cached separator.
+///
+/// ```
+/// core_reth_primitives::domain_tag! {
+///     /// Domain of block header commitments.
+///     pub struct HeaderDomain = b"core/header";
+/// }
+///
+/// let digest = core_reth_primitives::sha3_domain::<HeaderDomain>(b"header");
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! domain_tag {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident = $tag:expr;) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default)]
+        $vis struct $name;
+
+        impl $crate::DomainTag for $name {
+            const TAG: &'static [u8] = $tag;
+
+            fn separator() -> &'static $crate::DomainSeparator {
+                static SEPARATOR: ::std::sync::OnceLock<$crate::DomainSeparator> =
+                    ::std::sync::OnceLock::new();
+                SEPARATOR.get_or_init(|| $crate::DomainSeparator::new(Self::TAG))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    #[test]
+    fn matches_manual_pre_absorb() {
+        let contract = address!("00000000000000000000000000000000deadbeef");
+        let domain = DomainSeparator::new("core/test").with_chain_id(1).with_address(contract);
+
+        for item in [&b""[..], b"a", b"hello world"] {
+            let mut manual = Sha3::new();
+            manual.update(9u64.to_be_bytes());
+            manual.update(b"core/test");
+            manual.update([0x01]);
+            manual.update(1u64.to_be_bytes());
+            manual.update([0x02]);
+            manual.update(contract);
+            manual.update(item);
+
+            let expected = manual.finalize();
+            let mut hasher = domain.hasher();
+            hasher.update(item);
+            assert_eq!(hasher.finalize(), expected);
+            assert_eq!(domain.hash(item), expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn static_domain_matches_naive() {
+        crate::domain_tag! {
+            struct TestDomain = b"core/test";
+        }
+
+        for item in [&b""[..], b"a", b"hello world"] {
+            let mut naive = Sha3::new();
+            naive.update(9u64.to_be_bytes());
+            naive.update(b"core/test");
+            naive.update(item);
+            let expected = naive.finalize();
+
+            assert_eq!(sha3_domain::<TestDomain>(item), expected);
+            assert_eq!(DomainSeparator::new(TestDomain::TAG).hash(item), expected);
+        }
+        assert!(core::ptr::eq(TestDomain::separator(), TestDomain::separator()));
+    }
+
+    #[test]
+    fn domains_differ() {
+        let a = DomainSeparator::new("a");
+        let b = DomainSeparator::new("b");
+        assert_ne!(a.hash("x"), b.hash("x"));
+        assert_ne!(a.hash("x"), a.clone().with_chain_id(1).hash("x"));
+        assert_ne!(a.clone().with_chain_id(1).hash("x"), a.with_chain_id(2).hash("x"));
+    }
+
+    #[test]
+    fn fixed_layout() {
+        let contract = address!("00000000000000000000000000000000deadbeef");
+        let expected = DomainSeparator::new("core/test").with_chain_id(1).with_address(contract);
+
+        let reordered = DomainSeparator::new("core/test").with_address(contract).with_chain_id(1);
+        assert_eq!(reordered.hash("x"), expected.hash("x"));
+
+        // Repeated calls replace the earlier value instead of absorbing both.
+        let replaced = DomainSeparator::new("core/test")
+            .with_chain_id(2)
+            .with_address(Address::ZERO)
+            .with_chain_id(1)
+            .with_address(contract);
+        assert_eq!(replaced.hash("x"), expected.hash("x"));
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{sha3, Sha3};
+use alloc::string::String;
+use alloy_primitives::{Address, B256};
+
+/// The [EIP-712] `EIP712Domain` struct, hashed with SHA3-256 instead of Keccak-256.
+///
+/// Every field is optional. Fields that are `None` are left out of both the type string and the
+/// encoded data, as the spec requires, so the separator of a domain with only a name and a chain
+/// id is computed over `EIP712Domain(string name,uint256 chainId)`. The `salt` field is not
+/// supported.
+///
+/// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Eip712Domain {
+    /// The user readable name of the signing domain, e.g. the name of the DApp.
+    pub name: Option<String>,
+    /// The current major version of the signing domain.
+    pub version: Option<String>,
+    /// The chain id the signature is valid on.
+    pub chain_id: Option<u64>,
+    /// The address of the contract that will verify the signature.
+    pub verifying_contract: Option<Address>,
+}
+
+impl Eip712Domain {
+    /// Returns the domain separator, i.e. the SHA3-256 struct hash of the domain:
+    /// `sha3(sha3(type) || encodeData(domain))`.
+    ///
+    /// Strings are encoded as their SHA3-256 digest, the chain id as a big-endian `uint256` and
+    /// the contract address left-padded to 32 bytes, in the field order of the spec.
+    pub fn separator(&self) -> B256 {
+        let mut type_hasher = Sha3::new();
+        type_hasher.update("EIP712Domain(");
+        let fields = [
+            self.name.is_some().then_some("string name"),
+            self.version.is_some().then_some("string version"),
+            self.chain_id.is_some().then_some("uint256 chainId"),
+            self.verifying_contract.is_some().then_some("address verifyingContract"),
+        ];
+        for (i, field) in fields.into_iter().flatten().enumerate() {
+            if i != 0 {
+                type_hasher.update(",");
+            }
+            type_hasher.update(field);
+        }
+        type_hasher.update(")");
+
+        let mut hasher = Sha3::new();
+        hasher.update(type_hasher.finalize());
+        if let Some(name) = &self.name {
+            hasher.update(sha3(name));
+        }
+        if let Some(version) = &self.version {
+            hasher.update(sha3(version));
+        }
+        if let Some(chain_id) = self.chain_id {
+            hasher.update(B256::left_padding_from(&chain_id.to_be_bytes()));
+        }
+        if let Some(verifying_contract) = self.verifying_contract {
+            hasher.update(verifying_contract.into_word());
+        }
+        hasher.finalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloy_primitives::{address, b256};
+
+    // Vectors computed with Python's `hashlib.sha3_256`.
+    #[test]
+    fn separator_vectors() {
+        let domain = Eip712Domain {
+            name: Some("Ether Mail".to_string()),
+            version: Some("1".to_string()),
+            chain_id: Some(1),
+            verifying_contract: Some(address!("cccccccccccccccccccccccccccccccccccccccc")),
+        };
+        assert_eq!(
+            domain.separator(),
+            b256!("d7f1ff1a053fee282f99985f25b0099cbb1d7e3d978684ccefae2ded8ec94a7b")
+        );
+
+        let partial = Eip712Domain {
+            name: Some("Ether Mail".to_string()),
+            chain_id: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            partial.separator(),
+            b256!("4cf4f1d267d763479fd0336c3036170fe90e06d41a1251b5385cfe90cb18b5f4")
+        );
+
+        assert_eq!(Eip712Domain::default().separator(), sha3(sha3("EIP712Domain()")));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::Sha3;
+use alloy_primitives::B256;
+
+/// A SHA3-256 entropy pool mixing several sources into one seed, e.g. for a deterministic RNG.
+///
+/// Sources are absorbed in insertion order, each prefixed with its length as a big-endian `u64`
+/// (see [`Sha3::update_prefixed`]), so the seed depends on the order and the boundaries of the
+/// sources. Adding the same sources in the same order always yields the same seed.
+///
+/// The seed is only as unpredictable as the sources combined: mixing in timestamps and counters
+/// does not make up for the lack of a real entropy source.
+#[derive(Clone, Debug, Default)]
+pub struct Entropy {
+    hasher: Sha3,
+}
+
+impl Entropy {
+    /// Creates an empty pool.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mixes `source` into the pool.
+    #[inline]
+    pub fn add(&mut self, source: &[u8]) {
+        self.hasher.update_prefixed(source);
+    }
+
+    /// Returns the seed derived from all sources added so far. More sources can be added
+    /// afterwards.
+    #[inline]
+    pub fn seed(&self) -> B256 {
+        self.hasher.peek()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha3;
+
+    #[test]
+    fn seed_is_prefixed_concatenation() {
+        let mut entropy = Entropy::new();
+        assert_eq!(entropy.seed(), sha3(b""));
+
+        let time = 1_700_000_000u64.to_le_bytes();
+        entropy.add(&time);
+        entropy.add(b"counter 7");
+        entropy.add(b"");
+        let parts: [&[u8]; 5] =
+            [&8u64.to_be_bytes(), &time, &9u64.to_be_bytes(), b"counter 7", &0u64.to_be_bytes()];
+        let expected = sha3(parts.concat());
+        assert_eq!(entropy.seed(), expected);
+        assert_eq!(entropy.seed(), crate::sha3_tuple!(time, b"counter 7", b""));
+
+        let mut reordered = Entropy::new();
+        reordered.add(b"counter 7");
+        reordered.add(&time);
+        assert_ne!(reordered.seed(), entropy.seed());
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{LengthError, SignatureError};
+use core::fmt;
+
+/// A [`Result`](core::result::Result) with the crate-level [`Error`].
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+/// Errors returned by the fallible hashing and signature APIs of this crate.
+#[derive(Debug)]
+pub enum Error {
+    /// A buffer or byte container had the wrong length.
+    InvalidLength {
+        /// The expected length in bytes.
+        expected: usize,
+        /// The actual length in bytes.
+        got: usize,
+    },
+    /// A signature could not be decoded or recovered.
+    Signature(SignatureError),
+    /// A hasher was finalized without absorbing any input.
+    EmptyInput,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { expected, got } => {
+                write!(f, "invalid length: expected {expected} bytes, got {got}")
+            }
+            Self::Signature(err) => write!(f, "signature error: {err}"),
+            Self::EmptyInput => fmt::Display::fmt(&EmptyInputError, f),
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Signature(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<LengthError> for Error {
+    #[inline]
+    fn from(err: LengthError) -> Self {
+        Self::InvalidLength { expected: err.expected, got: err.got }
+    }
+}
+
+impl From<SignatureError> for Error {
+    #[inline]
+    fn from(err: SignatureError) -> Self {
+        Self::Signature(err)
+    }
+}
+
+impl From<EmptyInputError> for Error {
+    #[inline]
+    fn from(_: EmptyInputError) -> Self {
+        Self::EmptyInput
+    }
+}
+
+/// Error returned by [`Sha3::finalize_nonempty`](crate::Sha3::finalize_nonempty) when no input was
+/// absorbed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EmptyInputError;
+
+impl fmt::Display for EmptyInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("no input was absorbed")
+    }
+}
+
+impl core::error::Error for EmptyInputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn display() {
+        let err = Error::InvalidLength { expected: 32, got: 31 };
+        assert_eq!(err.to_string(), "invalid length: expected 32 bytes, got 31");
+
+        let inner = SignatureError::FromBytes("expected exactly 171 signature bytes");
+        let expected = format!("signature error: {inner}");
+        assert_eq!(Error::Signature(inner).to_string(), expected);
+
+        assert_eq!(Error::from(EmptyInputError).to_string(), "no input was absorbed");
+
+        let err = Error::from(LengthError { expected: 1368, got: 0 });
+        assert_eq!(err.to_string(), "invalid length: expected 1368 bytes, got 0");
+    }
+
+    #[test]
+    fn propagate_signature_error() {
+        fn decode(sig: &str) -> Result<crate::Signature> {
+            Ok(crate::decode_signature(sig, crate::SignatureEncoding::Hex)?)
+        }
+        assert!(matches!(decode("0xzz"), Err(Error::Signature(_))));
+    }
+}
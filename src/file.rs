@@ -0,0 +1,230 @@
+use crate::Sha3;
+use alloy_primitives::B256;
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
+
+/// Computes the SHA3-256 digest of the contents of the file at `path`.
+///
+/// The file is streamed in 8 KiB blocks and never read into memory at once.
+pub fn sha3_file<P: AsRef<Path>>(path: P) -> io::Result<B256> {
+    sha3_reader(&mut File::open(path)?)
+}
+
+/// Computes the SHA3-256 digest of everything read from `reader` until EOF.
+pub fn sha3_reader<R: Read>(reader: &mut R) -> io::Result<B256> {
+    let mut hasher = Sha3::new();
+    let mut buf = [0u8; 8192];
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        hasher.update(&buf[..n]);
+    }
+    Ok(hasher.finalize())
+}
+
+/// Streams `reader` until EOF and checks its SHA3-256 digest against `expected`.
+///
+/// This is the verification counterpart of [`sha3_reader`]. A digest can only be checked once all
+/// input was read, so there is no early exit on corrupted data. The comparison runs in constant
+/// time.
+pub fn verify_sha3<R: Read>(reader: &mut R, expected: B256) -> io::Result<bool> {
+    let digest = sha3_reader(reader)?;
+    Ok(crate::ct_eq(digest.as_slice(), expected.as_slice()))
+}
+
+/// Access pattern hint passed to the kernel by [`sha3_mmap_with_advice`].
+#[cfg(feature = "mmap")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MmapAdvice {
+    /// No special treatment, the kernel default.
+    Normal,
+    /// Pages are accessed in order, so read-ahead can be aggressive.
+    #[default]
+    Sequential,
+    /// Pages are accessed in random order, so read-ahead is pointless.
+    Random,
+    /// The whole mapping will be needed soon and can be read ahead right away.
+    WillNeed,
+}
+
+/// Computes the SHA3-256 digest of the contents of the file at `path` by memory-mapping it.
+///
+/// Same as [`sha3_mmap_with_advice`] with [`MmapAdvice::Sequential`], which matches how the
+/// mapping is hashed.
+#[cfg(feature = "mmap")]
+pub fn sha3_mmap<P: AsRef<Path>>(path: P) -> io::Result<B256> {
+    sha3_mmap_with_advice(path, MmapAdvice::Sequential)
+}
+
+/// Computes the SHA3-256 digest of the contents of the file at `path` by memory-mapping it, after
+/// passing `advice` to the kernel with `madvise`.
+///
+/// The advice is best-effort: it is only given on Unix, where the kernel is free to ignore it,
+/// and failing to give it is not an error. It only affects performance, never the digest, which
+/// equals [`sha3_file`] of the same file.
+///
+/// The file must not be modified while it is hashed, e.g. truncated by another process, as that
+/// can make the digest inconsistent or the process crash with `SIGBUS`.
+#[cfg(feature = "mmap")]
+pub fn sha3_mmap_with_advice<P: AsRef<Path>>(path: P, advice: MmapAdvice) -> io::Result<B256> {
+    let file = File::open(path)?;
+    if file.metadata()?.len() == 0 {
+        return Ok(crate::constants::SHA3_EMPTY);
+    }
+    // SAFETY: the mapping is only read, and modifications of the file while it is mapped are
+    // documented as unsupported above.
+    let mmap = unsafe { memmap2::Mmap::map(&file)? };
+    #[cfg(unix)]
+    {
+        let advice = match advice {
+            MmapAdvice::Normal => memmap2::Advice::Normal,
+            MmapAdvice::Sequential => memmap2::Advice::Sequential,
+            MmapAdvice::Random => memmap2::Advice::Random,
+            MmapAdvice::WillNeed => memmap2::Advice::WillNeed,
+        };
+        let _ = mmap.advise(advice);
+    }
+    #[cfg(not(unix))]
+    let _ = advice;
+
+    let mut hasher = Sha3::new();
+    for chunk in mmap.chunks(1 << 20) {
+        hasher.update(chunk);
+    }
+    Ok(hasher.finalize())
+}
+
+/// Number of segments read from the file per batch in [`sha3_file_parallel`].
+#[cfg(feature = "rayon")]
+const SEGMENTS_PER_BATCH: usize = 64;
+
+/// Computes a Merkle tree hash of the file at `path`, hashing `chunk_size`-byte segments in
+/// parallel.
+///
+/// Each segment is hashed with [`sha3`](crate::sha3), the last one possibly being shorter, and the
+/// segment digests are combined with [`merkle_root_par`](crate::merkle_root_par). The root only
+/// depends on the contents and `chunk_size`, not on the number of threads. It is a tree hash and
+/// differs from the linear [`sha3_file`] digest of the same file; an empty file has the root
+/// [`SHA3_EMPTY`](crate::constants::SHA3_EMPTY).
+///
+/// The file is read sequentially in batches of segments, so memory use is bounded by the batch
+/// size plus one digest per segment.
+///
+/// Panics if `chunk_size` is 0.
+#[cfg(feature = "rayon")]
+#[track_caller]
+pub fn sha3_file_parallel<P: AsRef<Path>>(path: P, chunk_size: usize) -> io::Result<B256> {
+    use rayon::prelude::*;
+
+    assert!(chunk_size != 0, "chunk size must be non-zero");
+    let file = File::open(path)?;
+    let batch = chunk_size.saturating_mul(SEGMENTS_PER_BATCH) as u64;
+    let mut leaves = alloc::vec::Vec::new();
+    let mut buf = alloc::vec::Vec::new();
+    loop {
+        buf.clear();
+        (&file).take(batch).read_to_end(&mut buf)?;
+        if buf.is_empty() {
+            break;
+        }
+        leaves.par_extend(buf.par_chunks(chunk_size).map(crate::sha3));
+    }
+    Ok(crate::merkle_root_par(&leaves))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha3;
+    use alloc::vec::Vec;
+    use std::path::PathBuf;
+
+    /// A file in the temp dir that is removed on drop.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str, contents: &[u8]) -> Self {
+            let path = std::env::temp_dir()
+                .join(alloc::format!("core-reth-primitives-{}-{name}", std::process::id()));
+            std::fs::write(&path, contents).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn contents(len: usize) -> Vec<u8> {
+        (0..=255).cycle().take(len).collect()
+    }
+
+    #[test]
+    fn file_matches_sha3() {
+        for len in [0, 1, 8192, 20_000] {
+            let data = contents(len);
+            let file = TempFile::new(&alloc::format!("linear-{len}"), &data);
+            assert_eq!(sha3_file(&file.0).unwrap(), sha3(&data));
+        }
+        assert!(sha3_file("/nonexistent/core-reth-primitives").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "mmap")]
+    fn mmap_matches_file() {
+        let data = contents(5 << 20);
+        let file = TempFile::new("mmap", &data);
+        let expected = sha3_file(&file.0).unwrap();
+        assert_eq!(sha3_mmap(&file.0).unwrap(), expected);
+        for advice in [MmapAdvice::Normal, MmapAdvice::Random, MmapAdvice::WillNeed] {
+            assert_eq!(sha3_mmap_with_advice(&file.0, advice).unwrap(), expected);
+        }
+
+        let empty = TempFile::new("mmap-empty", &[]);
+        assert_eq!(sha3_mmap(&empty.0).unwrap(), sha3(b""));
+        assert!(sha3_mmap("/nonexistent/core-reth-primitives").is_err());
+    }
+
+    #[test]
+    fn verify_stream() {
+        let data = contents(20_000);
+        let expected = sha3(&data);
+        assert!(verify_sha3(&mut data.as_slice(), expected).unwrap());
+
+        let mut corrupted = data.clone();
+        corrupted[12_345] ^= 1;
+        assert!(!verify_sha3(&mut corrupted.as_slice(), expected).unwrap());
+        assert!(!verify_sha3(&mut &data[..19_999], expected).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn parallel_is_deterministic() {
+        let data = contents(100_000);
+        let file = TempFile::new("parallel", &data);
+
+        let leaves: Vec<B256> = data.chunks(1000).map(sha3).collect();
+        let expected = crate::merkle_root(&leaves);
+        for threads in [1, 2, 8] {
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+            assert_eq!(pool.install(|| sha3_file_parallel(&file.0, 1000)).unwrap(), expected);
+        }
+        // 100_000 bytes are two full batches of 64 segments of 777 bytes, followed by a last
+        // batch holding only a partial 544-byte segment.
+        let leaves: Vec<B256> = data.chunks(777).map(sha3).collect();
+        assert_eq!(sha3_file_parallel(&file.0, 777).unwrap(), crate::merkle_root(&leaves));
+        assert_ne!(expected, sha3_file(&file.0).unwrap());
+
+        let empty = TempFile::new("parallel-empty", &[]);
+        assert_eq!(sha3_file_parallel(&empty.0, 1000).unwrap(), crate::constants::SHA3_EMPTY);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::Sha3;
+use alloy_primitives::{Address, B256};
+
+/// Types with a canonical SHA3-256 encoding.
+///
+/// Implementations absorb their fields with [`hash_into`](Self::hash_into) in a fixed order, so
+/// composite types can be hashed by delegating to the impls of their fields. Fixed-size values
+/// are absorbed as-is, integers in big-endian order, and variable-length byte strings are length
+/// prefixed with [`Sha3::update_prefixed`], which keeps the encoding of any sequence of fields
+/// unambiguous. Tuples absorb their elements in order.
+pub trait Hashable {
+    /// Absorbs the canonical encoding of `self` into `hasher`.
+    fn hash_into(&self, hasher: &mut Sha3);
+}
+
+/// Computes the SHA3-256 digest of the canonical encoding of `value`.
+#[inline]
+pub fn sha3_hashable<T: Hashable + ?Sized>(value: &T) -> B256 {
+    let mut hasher = Sha3::new();
+    value.hash_into(&mut hasher);
+    hasher.finalize()
+}
+
+macro_rules! impl_hashable_int {
+    ($($t:ty),*) => {$(
+        impl Hashable for $t {
+            #[inline]
+            fn hash_into(&self, hasher: &mut Sha3) {
+                hasher.update(self.to_be_bytes());
+            }
+        }
+    )*};
+}
+
+impl_hashable_int!(u8, u16, u32, u64);
+
+impl Hashable for [u8] {
+    #[inline]
+    fn hash_into(&self, hasher: &mut Sha3) {
+        hasher.update_prefixed(self);
+    }
+}
+
+impl Hashable for B256 {
+    #[inline]
+    fn hash_into(&self, hasher: &mut Sha3) {
+        hasher.update(self);
+    }
+}
+
+impl Hashable for Address {
+    #[inline]
+    fn hash_into(&self, hasher: &mut Sha3) {
+        hasher.update(self);
+    }
+}
+
+macro_rules! impl_hashable_tuple {
+    ($(($($name:ident),+)),*) => {$(
+        impl<$($name: Hashable),+> Hashable for ($($name,)+) {
+            #[inline]
+            #[allow(non_snake_case)]
+            fn hash_into(&self, hasher: &mut Sha3) {
+                let ($($name,)+) = self;
+                $($name.hash_into(hasher);)+
+            }
+        }
+    )*};
+}
+
+impl_hashable_tuple!((A, B), (A, B, C), (A, B, C, D));
+
+impl<T: Hashable + ?Sized> Hashable for &T {
+    #[inline]
+    fn hash_into(&self, hasher: &mut Sha3) {
+        (**self).hash_into(hasher);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Transfer<'a> {
+        to: Address,
+        amount: u64,
+        memo: &'a [u8],
+    }
+
+    impl Hashable for Transfer<'_> {
+        fn hash_into(&self, hasher: &mut Sha3) {
+            self.to.hash_into(hasher);
+            self.amount.hash_into(hasher);
+            self.memo.hash_into(hasher);
+        }
+    }
+
+    struct Batch<'a> {
+        nonce: u32,
+        transfers: [Transfer<'a>; 2],
+    }
+
+    impl Hashable for Batch<'_> {
+        fn hash_into(&self, hasher: &mut Sha3) {
+            self.nonce.hash_into(hasher);
+            for transfer in &self.transfers {
+                transfer.hash_into(hasher);
+            }
+        }
+    }
+
+    #[test]
+    fn nested_composition() {
+        let to = Address::repeat_byte(0x11);
+        let batch = Batch {
+            nonce: 7,
+            transfers: [
+                Transfer { to, amount: 1, memo: b"ab" },
+                Transfer { to, amount: 2, memo: b"" },
+            ],
+        };
+
+        let mut manual = Sha3::new();
+        manual.update(7u32.to_be_bytes());
+        for (amount, memo) in [(1u64, &b"ab"[..]), (2, b"")] {
+            manual.update(to);
+            manual.update(amount.to_be_bytes());
+            manual.update_prefixed(memo);
+        }
+        assert_eq!(sha3_hashable(&batch), manual.finalize());
+    }
+
+    #[test]
+    fn byte_strings_are_framed() {
+        let a = sha3_hashable(&(&b"a"[..], &b"bc"[..]));
+        assert_ne!(a, sha3_hashable(&(&b"ab"[..], &b"c"[..])));
+        assert_eq!(sha3_hashable(&b"abc"[..]), crate::sha3_tuple!(b"abc"));
+    }
+}
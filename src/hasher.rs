@@ -0,0 +1,121 @@
+use crate::Sha3;
+use core::hash::BuildHasher;
+#[cfg(feature = "getrandom")]
+use core::fmt;
+
+/// A [`core::hash::Hasher`] backed by SHA3-256.
+///
+/// [`finish`](core::hash::Hasher::finish) returns the first 8 bytes of the current digest,
+/// interpreted as a big-endian integer. This is much slower than the standard library's SipHash and
+/// only worth it where a cryptographic hash is required.
+#[derive(Clone, Debug, Default)]
+pub struct Sha3Hasher {
+    hasher: Sha3,
+}
+
+impl core::hash::Hasher for Sha3Hasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.hasher.update(bytes);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        u64::from_be_bytes(self.hasher.peek()[..8].try_into().unwrap())
+    }
+}
+
+/// A deterministic [`BuildHasher`] for [`Sha3Hasher`].
+///
+/// Every instance hashes a key to the same value, in every process. Use this where hashes must be
+/// reproducible, e.g. when they are persisted or compared across nodes, and keys are trusted.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sha3BuildHasher;
+
+impl BuildHasher for Sha3BuildHasher {
+    type Hasher = Sha3Hasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Sha3Hasher {
+        Sha3Hasher::default()
+    }
+}
+
+/// A randomly seeded [`BuildHasher`] for [`Sha3Hasher`].
+///
+/// Each instance absorbs a 32-byte seed from the operating system's random number generator before
+/// the key. Use this for hash tables keyed by untrusted input: an attacker who cannot predict the
+/// seed cannot craft keys that collide in the same bucket. Hashes are not reproducible across
+/// instances, see [`Sha3BuildHasher`] for that.
+#[cfg(feature = "getrandom")]
+#[derive(Clone)]
+pub struct Sha3RandomBuildHasher {
+    seeded: Sha3,
+}
+
+#[cfg(feature = "getrandom")]
+impl Sha3RandomBuildHasher {
+    /// Creates a new build hasher with a fresh random seed.
+    ///
+    /// Panics if the operating system's random number generator fails.
+    pub fn new() -> Self {
+        let mut seed = [0u8; 32];
+        getrandom::getrandom(&mut seed).expect("failed to seed Sha3RandomBuildHasher");
+        let mut seeded = Sha3::new();
+        seeded.update(seed);
+        Self { seeded }
+    }
+}
+
+#[cfg(feature = "getrandom")]
+impl Default for Sha3RandomBuildHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "getrandom")]
+impl fmt::Debug for Sha3RandomBuildHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sha3RandomBuildHasher").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "getrandom")]
+impl BuildHasher for Sha3RandomBuildHasher {
+    type Hasher = Sha3Hasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Sha3Hasher {
+        Sha3Hasher { hasher: self.seeded.clone() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha3;
+
+    #[test]
+    fn deterministic() {
+        let a = Sha3BuildHasher.hash_one("key");
+        assert_eq!(a, Sha3BuildHasher.hash_one("key"));
+        assert_ne!(a, Sha3BuildHasher.hash_one("kez"));
+
+        let mut hasher = Sha3BuildHasher.build_hasher();
+        core::hash::Hasher::write(&mut hasher, b"key");
+        let expected = u64::from_be_bytes(sha3(b"key")[..8].try_into().unwrap());
+        assert_eq!(core::hash::Hasher::finish(&hasher), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "getrandom")]
+    fn random_instances_differ() {
+        let a = Sha3RandomBuildHasher::new();
+        let b = Sha3RandomBuildHasher::new();
+        assert_eq!(a.hash_one("key"), a.hash_one("key"));
+        assert_ne!(a.hash_one("key"), b.hash_one("key"));
+        assert_ne!(a.hash_one("key"), Sha3BuildHasher.hash_one("key"));
+    }
+}
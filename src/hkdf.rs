@@ -0,0 +1,113 @@
+use crate::HmacSha3;
+use alloy_primitives::B256;
+use core::fmt;
+
+/// Maximum output length of [`hkdf_sha3`] in bytes: 255 blocks of the 32-byte hash length.
+pub const HKDF_MAX_OUTPUT: usize = 255 * 32;
+
+/// Error returned by [`hkdf_sha3`] when more than [`HKDF_MAX_OUTPUT`] bytes are requested.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HkdfLengthError {
+    /// The requested output length in bytes.
+    pub got: usize,
+}
+
+impl fmt::Display for HkdfLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "HKDF output of {} bytes exceeds the maximum of {HKDF_MAX_OUTPUT}", self.got)
+    }
+}
+
+impl core::error::Error for HkdfLengthError {}
+
+/// Derives `out.len()` bytes of key material from `ikm` with HKDF ([RFC 5869]) over
+/// [HMAC-SHA3-256](HmacSha3).
+///
+/// This is the extract step, `prk = HMAC(salt, ikm)`, followed by the expand step, which
+/// concatenates `T(i) = HMAC(prk, T(i - 1) || info || i)` for `i = 1, 2, ..` and truncates the
+/// result to `out.len()`. A missing or empty `salt` is replaced by 32 zero bytes, as the RFC
+/// specifies.
+///
+/// Fails without touching `out` if it is longer than [`HKDF_MAX_OUTPUT`].
+///
+/// [RFC 5869]: https://www.rfc-editor.org/rfc/rfc5869
+pub fn hkdf_sha3(
+    ikm: &[u8],
+    salt: Option<&[u8]>,
+    info: &[u8],
+    out: &mut [u8],
+) -> Result<(), HkdfLengthError> {
+    if out.len() > HKDF_MAX_OUTPUT {
+        return Err(HkdfLengthError { got: out.len() });
+    }
+
+    let salt = salt.filter(|salt| !salt.is_empty()).unwrap_or(&[0u8; 32]);
+    let mut extract = HmacSha3::new(salt);
+    extract.update(ikm);
+    let prk = extract.finalize();
+
+    let mut previous: Option<B256> = None;
+    for (counter, chunk) in (1..=u8::MAX).zip(out.chunks_mut(32)) {
+        let mut expand = HmacSha3::new(prk.as_slice());
+        if let Some(previous) = previous {
+            expand.update(previous);
+        }
+        expand.update(info);
+        expand.update([counter]);
+        let block = expand.finalize();
+        chunk.copy_from_slice(&block[..chunk.len()]);
+        previous = Some(block);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloy_primitives::hex;
+
+    // Inputs of RFC 5869 test case 1, with SHA3-256 output computed with Python's `hmac` and
+    // `hashlib.sha3_256`.
+    #[test]
+    fn hkdf_vectors() {
+        let ikm = [0x0b; 22];
+        let salt = hex!("000102030405060708090a0b0c");
+        let info = hex!("f0f1f2f3f4f5f6f7f8f9");
+
+        let mut out = [0u8; 42];
+        hkdf_sha3(&ikm, Some(&salt), &info, &mut out).unwrap();
+        assert_eq!(
+            out,
+            hex!(
+                "0c5160501d65021deaf2c14f5abce04c5bd2635abceeba61c2edb6e8ed72674900557728f2c9f2c4c179"
+            )
+        );
+
+        let expected = hex!(
+            "bc1342cdd75c05e8b0c3ae609ce4410684d197232875073499b30cdfe2de2853c1c1bed63d725e885e78"
+        );
+        for salt in [None, Some(&[][..]), Some(&[0u8; 32][..])] {
+            hkdf_sha3(&ikm, salt, &[], &mut out).unwrap();
+            assert_eq!(out, expected);
+        }
+
+        // Shorter outputs are prefixes of longer ones.
+        let mut short = [0u8; 7];
+        hkdf_sha3(&ikm, None, &[], &mut short).unwrap();
+        assert_eq!(short, expected[..7]);
+    }
+
+    #[test]
+    fn hkdf_max_length() {
+        let mut out = vec![0u8; HKDF_MAX_OUTPUT];
+        assert_eq!(hkdf_sha3(b"ikm", None, b"", &mut out), Ok(()));
+
+        let mut out = vec![0u8; HKDF_MAX_OUTPUT + 1];
+        assert_eq!(
+            hkdf_sha3(b"ikm", None, b"", &mut out),
+            Err(HkdfLengthError { got: HKDF_MAX_OUTPUT + 1 })
+        );
+        assert!(out.iter().all(|&b| b == 0));
+    }
+}
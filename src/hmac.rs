@@ -0,0 +1,179 @@
+use crate::{ct_eq, sha3, Sha3};
+use alloy_primitives::B256;
+use core::fmt;
+
+/// Block size of SHA3-256 in bytes, i.e. its rate.
+const BLOCK_SIZE: usize = 136;
+
+/// HMAC-SHA3-256, as defined in RFC 2104 with the SHA3-256 rate as block size.
+///
+/// With the `digest` feature this also implements the RustCrypto `Mac` trait.
+#[derive(Clone)]
+pub struct HmacSha3 {
+    inner: Sha3,
+    outer: Sha3,
+}
+
+impl fmt::Debug for HmacSha3 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HmacSha3").finish_non_exhaustive()
+    }
+}
+
+impl HmacSha3 {
+    /// Creates a new HMAC instance keyed with `key`. Keys longer than the block size are hashed
+    /// first.
+    pub fn new(key: &[u8]) -> Self {
+        let mut block = [0u8; BLOCK_SIZE];
+        if key.len() > BLOCK_SIZE {
+            block[..32].copy_from_slice(sha3(key).as_slice());
+        } else {
+            block[..key.len()].copy_from_slice(key);
+        }
+
+        let mut inner = Sha3::new();
+        inner.update(block.map(|b| b ^ 0x36));
+        let mut outer = Sha3::new();
+        outer.update(block.map(|b| b ^ 0x5c));
+        Self { inner, outer }
+    }
+
+    /// Absorbs additional input. Can be called multiple times.
+    #[inline]
+    pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        self.inner.update(bytes);
+    }
+
+    /// Returns the authentication tag.
+    ///
+    /// Dropping the tag fails to compile with `unused_must_use` denied:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// core_reth_primitives::HmacSha3::new(b"key").finalize();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn finalize(self) -> B256 {
+        let mut outer = self.outer;
+        outer.update(self.inner.finalize());
+        outer.finalize()
+    }
+
+    /// Verifies `tag` against the authentication tag in constant time.
+    #[inline]
+    pub fn verify(self, tag: &B256) -> bool {
+        ct_eq(self.finalize().as_slice(), tag.as_slice())
+    }
+}
+
+/// Computes the HMAC-SHA3-256 tag of `input` under `key`.
+///
+/// Dropping the tag fails to compile with `unused_must_use` denied:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// core_reth_primitives::hmac_sha3(b"key", b"hello world");
+/// ```
+#[must_use]
+pub fn hmac_sha3(key: &[u8], input: &[u8]) -> B256 {
+    let mut mac = HmacSha3::new(key);
+    mac.update(input);
+    mac.finalize()
+}
+
+#[cfg(feature = "digest")]
+mod digest_impl {
+    use super::HmacSha3;
+    use digest::{
+        consts::{U136, U32},
+        crypto_common::{InvalidLength, Key, KeyInit, KeySizeUser},
+        FixedOutput, MacMarker, Output, OutputSizeUser, Update,
+    };
+
+    impl KeySizeUser for HmacSha3 {
+        type KeySize = U136;
+    }
+
+    impl KeyInit for HmacSha3 {
+        #[inline]
+        fn new(key: &Key<Self>) -> Self {
+            Self::new(key.as_slice())
+        }
+
+        #[inline]
+        fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
+            Ok(Self::new(key))
+        }
+    }
+
+    impl OutputSizeUser for HmacSha3 {
+        type OutputSize = U32;
+    }
+
+    impl Update for HmacSha3 {
+        #[inline]
+        fn update(&mut self, data: &[u8]) {
+            self.inner.update(data);
+        }
+    }
+
+    impl FixedOutput for HmacSha3 {
+        #[inline]
+        fn finalize_into(self, out: &mut Output<Self>) {
+            out.copy_from_slice(self.finalize().as_slice());
+        }
+    }
+
+    impl MacMarker for HmacSha3 {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::b256;
+
+    // Test vectors computed with Python's `hmac` and `hashlib.sha3_256`.
+    const VECTORS: [(&[u8], &[u8], B256); 3] = [
+        (
+            b"key",
+            b"The quick brown fox jumps over the lazy dog",
+            b256!("8c6e0683409427f8931711b10ca92a506eb1fafa48fadd66d76126f47ac2c333"),
+        ),
+        (
+            &[b'k'; 200],
+            b"hello",
+            b256!("d8bb4a12ed59dec12bd8eeec45f195806679482a98271ddf0c815c580efcfe9f"),
+        ),
+        (b"", b"", b256!("e841c164e5b4f10c9f3985587962af72fd607a951196fc92fb3a5251941784ea")),
+    ];
+
+    #[test]
+    fn hmac_vectors() {
+        for (key, input, expected) in VECTORS {
+            assert_eq!(hmac_sha3(key, input), expected);
+
+            let mut mac = HmacSha3::new(key);
+            for chunk in input.chunks(5) {
+                mac.update(chunk);
+            }
+            assert!(mac.clone().verify(&expected));
+            assert!(!mac.verify(&B256::ZERO));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "digest")]
+    fn mac_trait_matches_native() {
+        use digest::Mac;
+
+        for (key, input, expected) in VECTORS {
+            let mut mac = <HmacSha3 as Mac>::new_from_slice(key).unwrap();
+            Mac::update(&mut mac, input);
+            assert_eq!(Mac::finalize(mac.clone()).into_bytes().as_slice(), expected.as_slice());
+            assert!(mac.clone().verify_slice(expected.as_slice()).is_ok());
+            assert!(mac.verify_slice(&[0u8; 32]).is_err());
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::Sha3;
+use alloy_primitives::B256;
+
+/// An iterator adapter that absorbs every yielded item into a running [`Sha3`] digest.
+///
+/// Items are passed through unchanged. The digest covers the concatenation of all items yielded
+/// so far, i.e. it equals [`sha3_iter`](crate::sha3_iter) over the same items.
+#[derive(Clone, Debug)]
+pub struct HashingIterator<I> {
+    iter: I,
+    hasher: Sha3,
+}
+
+impl<I> HashingIterator<I> {
+    /// Wraps `iter`.
+    #[inline]
+    pub fn new(iter: I) -> Self {
+        Self { iter, hasher: Sha3::new() }
+    }
+
+    /// Returns the digest of all items yielded so far.
+    ///
+    /// This clones the hasher state, so it can be called at any time without affecting the
+    /// running digest.
+    #[inline]
+    pub fn digest(&self) -> B256 {
+        self.hasher.clone().finalize()
+    }
+
+    /// Consumes the adapter and returns the digest of all items yielded so far.
+    ///
+    /// Items that were not yielded yet are not absorbed.
+    #[inline]
+    pub fn into_digest(self) -> B256 {
+        self.hasher.finalize()
+    }
+}
+
+impl<I> Iterator for HashingIterator<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.hasher.update(item.as_ref());
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{constants::SHA3_EMPTY, sha3, sha3_iter};
+
+    #[test]
+    fn digest_matches_sha3_iter() {
+        let items = ["hello", " ", "world", ""];
+        let mut iter = HashingIterator::new(items.iter());
+
+        assert_eq!(iter.digest(), SHA3_EMPTY);
+        assert_eq!(iter.next(), Some(&"hello"));
+        assert_eq!(iter.digest(), sha3("hello"));
+
+        let rest: usize = iter.by_ref().map(|item| item.len()).sum();
+        assert_eq!(rest, 6);
+        assert_eq!(iter.digest(), sha3("hello world"));
+        assert_eq!(iter.into_digest(), sha3_iter(items));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::Sha3;
+use alloc::{string::ToString, vec::Vec};
+use alloy_primitives::B256;
+use serde_json::Value;
+
+/// Computes the SHA3-256 digest of the canonical encoding of the JSON document `value`.
+///
+/// The canonical encoding is compact JSON with the keys of every object sorted by their UTF-8
+/// bytes, so documents that differ only in key order or formatting hash identically. The keys are
+/// sorted here, so this holds even if the `preserve_order` feature of [`serde_json`] is enabled.
+///
+/// Numbers are not normalized: they are encoded as parsed, so `1`, `1.0` and `1e0` hash
+/// differently even though they are equal as numbers. Strings are encoded with the minimal
+/// escaping of [`serde_json`], so equivalent escape sequences in the source, e.g. `"\u0041"` and
+/// `"A"`, hash identically.
+pub fn sha3_json(value: &Value) -> B256 {
+    let mut hasher = Sha3::new();
+    absorb(&mut hasher, value);
+    hasher.finalize()
+}
+
+fn absorb(hasher: &mut Sha3, value: &Value) {
+    match value {
+        Value::Array(items) => {
+            hasher.update(b"[");
+            for (i, item) in items.iter().enumerate() {
+                if i != 0 {
+                    hasher.update(b",");
+                }
+                absorb(hasher, item);
+            }
+            hasher.update(b"]");
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_unstable_by_key(|&(key, _)| key);
+            hasher.update(b"{");
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i != 0 {
+                    hasher.update(b",");
+                }
+                hasher.update(Value::String(key.clone()).to_string());
+                hasher.update(b":");
+                absorb(hasher, value);
+            }
+            hasher.update(b"}");
+        }
+        scalar => hasher.update(scalar.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha3;
+
+    #[test]
+    fn key_order_and_whitespace() {
+        let a: Value =
+            serde_json::from_str(r#"{"b": [1, {"d": 2, "c": 3}], "a": "A\n"}"#).unwrap();
+        let b: Value = serde_json::from_str(r#"{"a":"A\n","b":[1,{"c":3,"d":2}]}"#).unwrap();
+        assert_eq!(sha3_json(&a), sha3_json(&b));
+        assert_eq!(sha3_json(&a), sha3(r#"{"a":"A\n","b":[1,{"c":3,"d":2}]}"#));
+
+        let int: Value = serde_json::from_str("1").unwrap();
+        let float: Value = serde_json::from_str("1.0").unwrap();
+        assert_ne!(sha3_json(&int), sha3_json(&float));
+    }
+}
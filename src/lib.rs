@@ -1,9 +1,173 @@
+//! SHA3-256 hashing and signature primitives for Core.
+//!
+//! The crate is `no_std` and only requires `alloc`. The default `std` feature enables `std`
+//! support in the dependencies; run `cargo test-no-std` to test the `alloc`-only build.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod error;
+pub use error::{EmptyInputError, Error, Result};
+
 mod sha3;
-pub use sha3::{Sha3, sha3, eip191_hash_message, eip191_message};
+pub use sha3::{
+    Sha3, sha3, keccak256, sha3_batch, sha3_copy, sha3_counted, sha3_iter, sha3_limbs,
+    sha3_prefix, sha3_slices, limbs_to_b256, eip191_hash_message, eip191_hash_message_with_prefix,
+    eip191_message, eip191_message_checked, eip191_message_with_options, eip191_prepare,
+    is_probably_text, parse_eip191, rolling_digests, rolling_digests_with_stride, Eip191Hasher,
+    ParseEip191Error, Radix, EIP191_PREFIX, ETHEREUM_EIP191_PREFIX,
+};
+#[cfg(feature = "rayon")]
+pub use sha3::sha3_batch_par;
+#[cfg(feature = "crc")]
+pub use sha3::sha3_with_crc;
+#[cfg(feature = "digest")]
+pub use sha3::sha3_generic;
+
+mod algo;
+pub use algo::HashAlgo;
+
+mod bloom;
+pub use bloom::{Bloom, BLOOM_MAX_HASHES};
+
+#[cfg(feature = "capi")]
+pub mod capi;
+
+#[cfg(feature = "lru")]
+mod cache;
+#[cfg(feature = "lru")]
+pub use cache::VerificationCache;
+
+mod checkpoint;
+pub use checkpoint::CheckpointHasher;
+
+mod cid;
+pub use cid::{ContentId, ParseContentIdError};
+#[cfg(feature = "uuid")]
+pub use cid::deterministic_uuid;
+
+mod commit;
+pub use commit::{commit, verify_reveal, RollingCommit};
+#[cfg(feature = "getrandom")]
+pub use commit::random_nonce;
+
+mod digests;
+pub use digests::{digest_diff, is_sorted_digests, sort_digests, xor_digests, Sha3Digest};
+
+mod domain;
+pub use domain::{sha3_domain, DomainSeparator, DomainTag};
+
+mod eip712;
+pub use eip712::Eip712Domain;
+
+mod entropy;
+pub use entropy::Entropy;
+
+#[cfg(feature = "std")]
+mod file;
+#[cfg(feature = "std")]
+pub use file::{sha3_file, sha3_reader, verify_sha3};
+#[cfg(feature = "mmap")]
+pub use file::{sha3_mmap, sha3_mmap_with_advice, MmapAdvice};
+#[cfg(feature = "rayon")]
+pub use file::sha3_file_parallel;
+
+mod hashable;
+pub use hashable::{sha3_hashable, Hashable};
+
+mod hasher;
+pub use hasher::{Sha3BuildHasher, Sha3Hasher};
+#[cfg(feature = "getrandom")]
+pub use hasher::Sha3RandomBuildHasher;
+
+mod hkdf;
+pub use hkdf::{hkdf_sha3, HkdfLengthError, HKDF_MAX_OUTPUT};
+
+mod hmac;
+pub use hmac::{hmac_sha3, HmacSha3};
+
+mod iter;
+pub use iter::HashingIterator;
+
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "serde_json")]
+pub use json::sha3_json;
+
+mod mac;
+pub use mac::{ct_eq, keyed_hash, keyed_verify};
+
+mod merkle;
+pub use merkle::{merkle_leaf_hash, merkle_node_hash, merkle_root};
+#[cfg(feature = "rayon")]
+pub use merkle::{merkle_root_par, merkle_root_par_with_chunk, DEFAULT_PAR_CHUNK};
+
+mod message;
+pub use message::{ParseSignedMessageError, SignedMessage};
+
+mod metered;
+pub use metered::MeteredSha3;
+
+mod ring;
+pub use ring::{build_ring, consistent_hash, ring_point};
+
+mod scalar;
+pub use scalar::hash_to_scalar;
+
+mod shake;
+pub use shake::{CountingShake, Shake, ShakeBlocks};
+
+mod shuffle;
+pub use shuffle::{shuffle_deterministic, weighted_select};
+
+#[cfg(feature = "unstable")]
+mod sponge;
+#[cfg(feature = "unstable")]
+pub use sponge::KeccakSponge;
+
+mod stretch;
+pub use stretch::slow_hash;
+
+#[cfg(feature = "serde")]
+pub mod serde_signature;
+
+mod signature;
+pub use signature::{
+    decode_signature, login_message, recover_address_from_msg, recover_address_from_prehash,
+    recover_and_verify_eip191, recover_eip191_any_prefix, recover_login, verify_eip191,
+    verify_eip191_any, verify_eip191_str, RecoveryMemo, SignatureEncoding, StreamingVerifier,
+    VerificationError, SIGNATURE_LENGTH,
+};
+#[cfg(feature = "std")]
+pub use signature::verify_eip191_reader;
+
+pub mod trie;
+
+#[cfg(feature = "serde")]
+mod typed;
+#[cfg(feature = "serde")]
+pub use typed::hash_typed;
 
 pub mod constants;
 
+#[cfg(feature = "testing")]
+pub mod testing;
+
 use alloy_primitives::FixedBytes;
 pub type B1368 = FixedBytes<1368>;
 
+mod b1368;
+pub use b1368::{
+    b1368_and, b1368_as_u64_limbs_be, b1368_as_u64_limbs_le, b1368_from_bytes, b1368_from_hex,
+    b1368_from_slice, b1368_from_u64_limbs_be, b1368_from_u64_limbs_le, b1368_get_bit, b1368_not,
+    b1368_or, b1368_set_bit, b1368_slice, b1368_slice_mut, b1368_to_hex_chunked, b1368_to_vec,
+    b1368_xor, LengthError, B1368_BITS, B1368_LIMBS,
+};
+
 pub use base_primitives::{Signature, SignatureError};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloy_primitives;
+}
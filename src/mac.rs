@@ -0,0 +1,70 @@
+use crate::Sha3;
+use alloy_primitives::B256;
+
+/// Computes a keyed SHA3-256 digest of `input`: `sha3(key || input)`.
+///
+/// # Security
+///
+/// This is a keyed sponge, not HMAC. Because SHA3 is not vulnerable to length extension, prefixing
+/// a secret key is a sound MAC construction for a fixed-length key, but it is not interoperable
+/// with HMAC-SHA3-256 and must not be used where HMAC is required by a specification.
+pub fn keyed_hash(key: &B256, input: &[u8]) -> B256 {
+    let mut hasher = Sha3::new();
+    hasher.update(key);
+    hasher.update(input);
+    hasher.finalize()
+}
+
+/// Verifies that `tag` is the [`keyed_hash`] of `input` under `key`.
+///
+/// The comparison runs in constant time.
+pub fn keyed_verify(key: &B256, input: &[u8], tag: &B256) -> bool {
+    ct_eq(keyed_hash(key, input).as_slice(), tag.as_slice())
+}
+
+/// Compares two byte slices in constant time with respect to their contents.
+///
+/// The length of the slices is not considered secret: slices of different lengths compare unequal
+/// immediately.
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    core::hint::black_box(diff) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha3;
+
+    #[test]
+    fn keyed_hash_is_key_then_input() {
+        let key = B256::repeat_byte(0x42);
+        let expected = sha3([key.as_slice(), b"hello world"].concat());
+        assert_eq!(keyed_hash(&key, b"hello world"), expected);
+        assert_ne!(keyed_hash(&B256::ZERO, b"hello world"), expected);
+    }
+
+    #[test]
+    fn keyed_verify_tag() {
+        let key = B256::repeat_byte(0x42);
+        let tag = keyed_hash(&key, b"token");
+        assert!(keyed_verify(&key, b"token", &tag));
+        assert!(!keyed_verify(&key, b"tokem", &tag));
+        assert!(!keyed_verify(&B256::repeat_byte(0x43), b"token", &tag));
+
+        let mut tampered = tag;
+        tampered[31] ^= 1;
+        assert!(!keyed_verify(&key, b"token", &tampered));
+    }
+
+    #[test]
+    fn ct_eq_slices() {
+        assert!(ct_eq(b"", b""));
+        assert!(ct_eq(b"abc", b"abc"));
+        assert!(!ct_eq(b"abc", b"abd"));
+        assert!(!ct_eq(b"abc", b"ab"));
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{constants::SHA3_EMPTY, Sha3};
+use alloc::vec::Vec;
+use alloy_primitives::B256;
+
+/// Default number of node pairs hashed per rayon task in [`merkle_root_par`].
+#[cfg(feature = "rayon")]
+pub const DEFAULT_PAR_CHUNK: usize = 256;
+
+/// Domain byte absorbed in front of a leaf.
+const LEAF_DOMAIN: u8 = 0x00;
+/// Domain byte absorbed in front of an interior node's children.
+const NODE_DOMAIN: u8 = 0x01;
+
+/// Hashes a leaf of a Merkle tree: `sha3(0x00 || data)`.
+///
+/// Leaves and interior nodes are hashed under distinct domain bytes, as in [RFC 6962], so a leaf
+/// can never be mistaken for an interior node. Without this, the concatenation of two children
+/// could be presented as a leaf of a shorter tree with the same root (a second preimage).
+///
+/// [RFC 6962]: https://www.rfc-editor.org/rfc/rfc6962#section-2.1
+#[inline]
+pub fn merkle_leaf_hash(data: &[u8]) -> B256 {
+    let mut hasher = Sha3::new();
+    hasher.update_delimited(LEAF_DOMAIN, data);
+    hasher.finalize()
+}
+
+/// Hashes two sibling nodes into their parent: `sha3(0x01 || left || right)`.
+///
+/// See [`merkle_leaf_hash`] for the domain separation.
+#[inline]
+pub fn merkle_node_hash(left: &B256, right: &B256) -> B256 {
+    let mut hasher = Sha3::new();
+    hasher.update_delimited(NODE_DOMAIN, left);
+    hasher.update(right);
+    hasher.finalize()
+}
+
+/// Hashes a leaf digest into the bottom level of the tree.
+#[inline]
+fn hash_leaf(leaf: &B256) -> B256 {
+    merkle_leaf_hash(leaf.as_slice())
+}
+
+/// Hashes one level of the tree into the next. An odd last node is promoted unchanged.
+#[inline]
+fn hash_pair(pair: &[B256]) -> B256 {
+    match pair {
+        [left, right] => merkle_node_hash(left, right),
+        [node] => *node,
+        _ => unreachable!("chunks of at most two nodes"),
+    }
+}
+
+/// Computes the root of the binary Merkle tree over `leaves`.
+///
+/// Each leaf is first hashed with [`merkle_leaf_hash`]. Levels are then built bottom-up by hashing
+/// adjacent pairs with [`merkle_node_hash`]; an odd node at the end of a level is promoted to the
+/// next level unchanged. The root of a single leaf is its leaf hash, and the root of no leaves is
+/// [`SHA3_EMPTY`].
+pub fn merkle_root(leaves: &[B256]) -> B256 {
+    if leaves.is_empty() {
+        return SHA3_EMPTY;
+    }
+    let mut level: Vec<B256> = leaves.iter().map(hash_leaf).collect();
+    while level.len() > 1 {
+        level = level.chunks(2).map(hash_pair).collect();
+    }
+    level[0]
+}
+
+/// Parallel version of [`merkle_root`], producing the identical root.
+///
+/// Each level is hashed on the rayon thread pool, [`DEFAULT_PAR_CHUNK`] pairs per task.
+#[cfg(feature = "rayon")]
+pub fn merkle_root_par(leaves: &[B256]) -> B256 {
+    merkle_root_par_with_chunk(leaves, DEFAULT_PAR_CHUNK)
+}
+
+/// Same as [`merkle_root_par`], but hashes at least `chunk` pairs per rayon task.
+///
+/// Larger chunks reduce scheduling overhead, smaller chunks balance the load better on small
+/// levels.
+#[cfg(feature = "rayon")]
+pub fn merkle_root_par_with_chunk(leaves: &[B256], chunk: usize) -> B256 {
+    use rayon::prelude::*;
+
+    if leaves.is_empty() {
+        return SHA3_EMPTY;
+    }
+    let chunk = chunk.max(1);
+    let mut level: Vec<B256> = leaves.par_iter().with_min_len(chunk).map(hash_leaf).collect();
+    while level.len() > 1 {
+        level = level.par_chunks(2).with_min_len(chunk).map(hash_pair).collect();
+    }
+    level[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha3;
+
+    fn leaves(n: usize) -> Vec<B256> {
+        (0..n as u64).map(|i| sha3(i.to_be_bytes())).collect()
+    }
+
+    #[test]
+    fn small_trees() {
+        let l = leaves(3);
+        let h: Vec<B256> = l.iter().map(hash_leaf).collect();
+        assert_eq!(merkle_root(&[]), SHA3_EMPTY);
+        assert_eq!(merkle_root(&l[..1]), h[0]);
+        assert_eq!(merkle_root(&l[..2]), merkle_node_hash(&h[0], &h[1]));
+        assert_eq!(merkle_root(&l), merkle_node_hash(&merkle_node_hash(&h[0], &h[1]), &h[2]));
+    }
+
+    #[test]
+    fn leaf_and_node_hashes_differ() {
+        let (a, b) = (sha3("a"), sha3("b"));
+        let concat = [a.as_slice(), b.as_slice()].concat();
+        assert_ne!(merkle_leaf_hash(&concat), merkle_node_hash(&a, &b));
+        assert_eq!(merkle_leaf_hash(&concat), sha3([&[0x00], &concat[..]].concat()));
+        assert_eq!(merkle_node_hash(&a, &b), sha3([&[0x01], &concat[..]].concat()));
+        // A two-leaf root cannot be reproduced by a single leaf holding both children.
+        assert_ne!(merkle_root(&[a, b]), merkle_root(&[merkle_leaf_hash(&concat)]));
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn par_matches_sequential() {
+        for n in [0, 1, 2, 7, 1000, 4097] {
+            let l = leaves(n);
+            let expected = merkle_root(&l);
+            assert_eq!(merkle_root_par(&l), expected);
+            for chunk in [0, 1, 3, 64] {
+                assert_eq!(merkle_root_par_with_chunk(&l, chunk), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::eip191_hash_message;
+use alloc::string::{String, ToString};
+use alloy_primitives::B256;
+use core::{fmt, str::FromStr};
+
+/// A textual message envelope for EIP-191 signing.
+///
+/// The envelope format is `core-sign:<message>`: the literal [`SignedMessage::PREFIX`] followed by
+/// the message verbatim. The message may be empty and may itself contain `:`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SignedMessage {
+    message: String,
+}
+
+impl SignedMessage {
+    /// The envelope prefix.
+    pub const PREFIX: &'static str = "core-sign:";
+
+    /// Creates a new envelope around `message`.
+    #[inline]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into() }
+    }
+
+    /// Returns the enveloped message.
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the EIP-191 hash of the enveloped message.
+    #[inline]
+    pub fn hash(&self) -> B256 {
+        eip191_hash_message(&self.message)
+    }
+}
+
+impl fmt::Display for SignedMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", Self::PREFIX, self.message)
+    }
+}
+
+impl FromStr for SignedMessage {
+    type Err = ParseSignedMessageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.strip_prefix(Self::PREFIX)
+            .map(|message| Self { message: message.to_string() })
+            .ok_or(ParseSignedMessageError::MissingPrefix)
+    }
+}
+
+/// Error returned when parsing a [`SignedMessage`] envelope fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseSignedMessageError {
+    /// The input does not start with [`SignedMessage::PREFIX`].
+    MissingPrefix,
+}
+
+impl fmt::Display for ParseSignedMessageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPrefix => {
+                write!(f, "signed message envelope must start with `{}`", SignedMessage::PREFIX)
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseSignedMessageError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_envelope() {
+        let msg: SignedMessage = "core-sign:Hello World".parse().unwrap();
+        assert_eq!(msg.message(), "Hello World");
+        assert_eq!(msg.hash(), eip191_hash_message("Hello World"));
+        assert_eq!(msg.to_string(), "core-sign:Hello World");
+
+        let msg: SignedMessage = "core-sign:".parse().unwrap();
+        assert_eq!(msg, SignedMessage::new(""));
+
+        let msg: SignedMessage = "core-sign:a:b".parse().unwrap();
+        assert_eq!(msg.message(), "a:b");
+    }
+
+    #[test]
+    fn parse_malformed() {
+        for s in ["", "Hello World", "core-sign", "CORE-SIGN:x", " core-sign:x"] {
+            assert_eq!(s.parse::<SignedMessage>(), Err(ParseSignedMessageError::MissingPrefix));
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::Sha3;
+use alloy_primitives::B256;
+
+/// Rate of SHA3-256 in bytes, i.e. the number of input bytes absorbed per permutation.
+const RATE: u64 = 136;
+
+/// A [`Sha3`] hasher that counts the keccak-f permutations needed to compute its digest, for
+/// deterministic metering of hashing cost.
+///
+/// Every full `136`-byte block of input is permuted once, and finalization pads the remaining
+/// `0..136` bytes into one more block, which also yields the 32-byte digest without another
+/// permutation. Hashing `len` bytes therefore costs `len / 136 + 1` permutations, which only
+/// depends on the total length and not on how the input is split across
+/// [`update`](Self::update) calls.
+#[derive(Clone, Debug, Default)]
+pub struct MeteredSha3 {
+    hasher: Sha3,
+    len: u64,
+}
+
+impl MeteredSha3 {
+    /// Creates a new [`MeteredSha3`] hasher.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs additional input. Can be called multiple times.
+    #[inline]
+    pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        let bytes = bytes.as_ref();
+        self.len += bytes.len() as u64;
+        self.hasher.update(bytes);
+    }
+
+    /// Returns the number of permutations run by [`finalize`](Self::finalize) over the input
+    /// absorbed so far, including the final padded block.
+    #[inline]
+    pub const fn permutations(&self) -> u64 {
+        self.len / RATE + 1
+    }
+
+    /// Pad and squeeze the state.
+    #[inline]
+    #[must_use]
+    pub fn finalize(self) -> B256 {
+        self.hasher.finalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha3;
+    use alloc::vec;
+
+    #[test]
+    fn permutation_count() {
+        let cases = [(0, 1), (1, 1), (135, 1), (136, 2), (137, 2), (272, 3), (1000, 8)];
+        for (len, expected) in cases {
+            let input = vec![0xab; len];
+            for chunk_size in [1, 7, 136, 1000] {
+                let mut hasher = MeteredSha3::new();
+                for chunk in input.chunks(chunk_size) {
+                    hasher.update(chunk);
+                }
+                assert_eq!(hasher.permutations(), expected, "len {len}, chunks of {chunk_size}");
+                assert_eq!(hasher.finalize(), sha3(&input));
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{sha3, Sha3};
+use alloc::vec::Vec;
+use alloy_primitives::B256;
+
+/// Returns the position of virtual node `vnode` of `node` on a consistent hashing ring.
+///
+/// The position is `sha3(len(node) || node || vnode)`, with the length and `vnode` encoded as
+/// big-endian `u64`s. Use it to map the points returned by [`build_ring`] back to their nodes.
+pub fn ring_point(node: &[u8], vnode: usize) -> B256 {
+    let mut hasher = Sha3::new();
+    hasher.update_prefixed(node);
+    hasher.update((vnode as u64).to_be_bytes());
+    hasher.finalize()
+}
+
+/// Builds a consistent hashing ring with `vnodes` virtual nodes per node, sorted ascending.
+///
+/// More virtual nodes spread the keys more evenly over the nodes. The ring only depends on the
+/// set of nodes, not on their order.
+pub fn build_ring(nodes: &[&[u8]], vnodes: usize) -> Vec<B256> {
+    let mut ring: Vec<B256> = nodes
+        .iter()
+        .flat_map(|node| (0..vnodes).map(move |vnode| ring_point(node, vnode)))
+        .collect();
+    ring.sort_unstable();
+    ring
+}
+
+/// Returns the index of the ring point responsible for `key`: the first point of the sorted
+/// `ring` greater than or equal to `sha3(key)`, wrapping around to 0 past the last point.
+///
+/// Removing a node from the ring only moves the keys of its own points, each to the next point
+/// on the ring; all other keys keep their point.
+///
+/// Panics if `ring` is empty.
+#[track_caller]
+pub fn consistent_hash(key: &[u8], ring: &[B256]) -> usize {
+    assert!(!ring.is_empty(), "consistent hashing ring is empty");
+    let hash = sha3(key);
+    let index = ring.partition_point(|point| *point < hash);
+    if index == ring.len() {
+        0
+    } else {
+        index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+
+    #[test]
+    fn ring_is_sorted_and_order_independent() {
+        let ring = build_ring(&[b"a", b"b", b"c"], 10);
+        assert_eq!(ring.len(), 30);
+        assert!(crate::is_sorted_digests(&ring));
+        assert_eq!(ring, build_ring(&[b"c", b"a", b"b"], 10));
+        assert!(ring.contains(&ring_point(b"b", 9)));
+        assert!(build_ring(&[], 10).is_empty());
+    }
+
+    #[test]
+    fn keys_map_stably_and_rebalance_minimally() {
+        let nodes: [&[u8]; 3] = [b"node-a", b"node-b", b"node-c"];
+        let owners: BTreeMap<B256, &[u8]> = nodes
+            .iter()
+            .flat_map(|&node| (0..16).map(move |vnode| (ring_point(node, vnode), node)))
+            .collect();
+        let ring = build_ring(&nodes, 16);
+        let smaller = build_ring(&nodes[..2], 16);
+
+        let mut moved = 0;
+        for i in 0..1000u32 {
+            let key = i.to_be_bytes();
+            let point = ring[consistent_hash(&key, &ring)];
+            assert_eq!(point, ring[consistent_hash(&key, &ring)]);
+
+            let hash = sha3(key);
+            assert!(point >= hash || point == ring[0]);
+
+            let after = smaller[consistent_hash(&key, &smaller)];
+            if owners[&point] == b"node-c" {
+                assert_ne!(owners[&after], b"node-c");
+                moved += 1;
+            } else {
+                assert_eq!(after, point);
+            }
+        }
+        assert!(moved > 0 && moved < 1000);
+    }
+
+    #[test]
+    #[should_panic = "ring is empty"]
+    fn empty_ring() {
+        consistent_hash(b"key", &[]);
+    }
+}
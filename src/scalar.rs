@@ -0,0 +1,50 @@
+use crate::{constants::SECP256K1_ORDER, Sha3};
+use alloy_primitives::U512;
+
+/// Hashes `input` to a secp256k1 scalar, i.e. an integer in `[0, n)` where `n` is
+/// [`SECP256K1_ORDER`], returned as 32 big-endian bytes.
+///
+/// To keep the modulo bias negligible, the input is first expanded to 512 bits as
+/// `sha3(0x00 || input) || sha3(0x01 || input)` and this wide value is then reduced modulo `n`.
+/// The bias of the result is below `2^-256`.
+pub fn hash_to_scalar(input: &[u8]) -> [u8; 32] {
+    let mut wide = [0u8; 64];
+    for (i, half) in wide.chunks_exact_mut(32).enumerate() {
+        let mut hasher = Sha3::new();
+        hasher.update([i as u8]);
+        hasher.update(input);
+        hasher.finalize_into(half);
+    }
+
+    let n = U512::from_be_slice(SECP256K1_ORDER.as_slice());
+    let scalar = U512::from_be_bytes(wide) % n;
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&scalar.to_be_bytes::<64>()[32..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{b256, B256};
+
+    #[test]
+    fn known_scalars() {
+        assert_eq!(
+            B256::from(hash_to_scalar(b"hello world")),
+            b256!("6cd0512329d7e2316f25233de193d240e1ad2c0d3ac64732de050a3ba0fe34cc")
+        );
+        assert_eq!(
+            B256::from(hash_to_scalar(b"")),
+            b256!("b7f2c4e1e31b6901580ef75850849072a6322dd88d40eb264564fc68ba9f38cf")
+        );
+    }
+
+    #[test]
+    fn scalar_is_below_order() {
+        for i in 0u32..64 {
+            let scalar = hash_to_scalar(&i.to_be_bytes());
+            assert!(scalar < SECP256K1_ORDER.0);
+        }
+    }
+}
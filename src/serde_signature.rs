@@ -0,0 +1,47 @@
+//! Serde support for [`Signature`] as a `0x`-prefixed hex string, matching the representation of
+//! digests.
+//!
+//! Use with `#[serde(with = "core_reth_primitives::serde_signature")]`.
+
+use crate::{decode_signature, Signature, SignatureEncoding};
+use alloc::string::String;
+use alloy_primitives::hex;
+use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+/// Serializes `sig` as a `0x`-prefixed hex string of its 171 bytes.
+pub fn serialize<S: Serializer>(sig: &Signature, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&hex::encode_prefixed(sig.as_bytes()))
+}
+
+/// Deserializes a [`Signature`] from a hex string, failing on invalid hex or a length other than
+/// 171 bytes.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Signature, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    decode_signature(&s, SignatureEncoding::Hex).map_err(D::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SIGNATURE_LENGTH;
+    use alloc::vec::Vec;
+    use serde_json::Value;
+
+    #[test]
+    fn roundtrip() {
+        let bytes: Vec<u8> = (0..SIGNATURE_LENGTH as u8).collect();
+        let sig = Signature::try_from(bytes.as_slice()).unwrap();
+
+        let json = serialize(&sig, serde_json::value::Serializer).unwrap();
+        assert_eq!(json, Value::String(hex::encode_prefixed(&bytes)));
+        assert_eq!(deserialize(json).unwrap(), sig);
+    }
+
+    #[test]
+    fn malformed() {
+        let short = hex::encode_prefixed([0u8; SIGNATURE_LENGTH - 1]);
+        assert!(deserialize(Value::String(short)).is_err());
+        assert!(deserialize(Value::String("0xzz".into())).is_err());
+        assert!(deserialize(Value::from(42)).is_err());
+    }
+}
@@ -1,17 +1,32 @@
-use alloy_primitives::B256;
+use crate::{EmptyInputError, Error};
+use alloc::vec::Vec;
+use alloy_primitives::{hex, Address, FixedBytes, B256};
 use core::mem::MaybeUninit;
 use tiny_keccak::Hasher as _;
 use core::fmt;
+#[cfg(feature = "digest")]
+use digest::{consts::U32, generic_array::GenericArray};
 
 pub const EIP191_PREFIX: &str = "\x19Core Signed Message:\n";
 
+/// The [EIP-191] prefix used by Ethereum wallets, for interoperability with
+/// [`eip191_hash_message_with_prefix`].
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+pub const ETHEREUM_EIP191_PREFIX: &str = "\x19Ethereum Signed Message:\n";
+
 /// Simple [`Sha3-256`] hasher.
 ///
 /// Note that the "native-keccak" feature is not supported for this struct, and will default to the
 /// [`tiny_keccak`] implementation.
+///
+/// The sponge state of [`tiny_keccak`] is private, so an in-progress hasher cannot be exported,
+/// serialized or resumed elsewhere; checkpoint the absorbed input instead. The `KeccakSponge`
+/// behind the `unstable` feature exposes its raw state words.
 #[derive(Clone)]
 pub struct Sha3 {
     hasher: tiny_keccak::Sha3,
+    len: u64,
 }
 
 impl Default for Sha3 {
@@ -28,21 +43,80 @@ impl fmt::Debug for Sha3 {
     }
 }
 
+impl Extend<u8> for Sha3 {
+    /// Absorbs the bytes of `iter`, see [`Sha3::update_bytes`].
+    #[inline]
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        self.update_bytes(iter);
+    }
+}
+
+impl<'a> Extend<&'a u8> for Sha3 {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a u8>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<'a> Extend<&'a [u8]> for Sha3 {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a [u8]>>(&mut self, iter: I) {
+        for bytes in iter {
+            self.update(bytes);
+        }
+    }
+}
+
 impl Sha3 {
     /// Creates a new [`Sha3`] hasher.
     #[inline]
     pub fn new() -> Self {
-        Self { hasher: tiny_keccak::Sha3::v256() }
+        Self { hasher: tiny_keccak::Sha3::v256(), len: 0 }
+    }
+
+    /// Resets the hasher to its initial state, discarding all absorbed input.
+    ///
+    /// A [`Sha3`] owns no heap memory, so this costs the same as [`new`](Self::new) and there is
+    /// nothing to gain from pooling hashers for reuse.
+    #[inline]
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns the total number of bytes absorbed so far.
+    #[inline]
+    pub const fn bytes_absorbed(&self) -> u64 {
+        self.len
+    }
+
+    /// Pad and squeeze the state, and reset the hasher so it can be reused.
+    #[inline]
+    #[must_use]
+    pub fn finalize_reset(&mut self) -> B256 {
+        core::mem::take(self).finalize()
     }
 
     /// Absorbs additional input. Can be called multiple times.
     #[inline]
     pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
-        self.hasher.update(bytes.as_ref());
+        let bytes = bytes.as_ref();
+        if bytes.is_empty() {
+            return;
+        }
+        self.len += bytes.len() as u64;
+        self.hasher.update(bytes);
     }
 
     /// Pad and squeeze the state.
+    ///
+    /// Dropping the digest fails to compile with `unused_must_use` denied:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// core_reth_primitives::Sha3::new().finalize();
+    /// ```
     #[inline]
+    #[must_use]
     pub fn finalize(self) -> B256 {
         let mut output = MaybeUninit::<B256>::uninit();
         // SAFETY: The output is 32-bytes.
@@ -51,6 +125,169 @@ impl Sha3 {
         unsafe { output.assume_init() }
     }
 
+    /// Absorbs the bytes of `value`, e.g. a [`B256`], an [`Address`] or a
+    /// [`B1368`](crate::B1368).
+    ///
+    /// Equivalent to `update(value.as_slice())`. [`Address`] derefs to `FixedBytes<20>`, so pass it
+    /// as `&address.0`.
+    #[inline]
+    pub fn update_fixed<const N: usize>(&mut self, value: &FixedBytes<N>) {
+        self.update(value.as_slice());
+    }
+
+    /// Absorbs the bytes yielded by `iter`, e.g. by a lazy decoder.
+    ///
+    /// The bytes are buffered in a 64-byte stack buffer and absorbed chunk by chunk, so memory use
+    /// is bounded regardless of the length of the iterator.
+    pub fn update_bytes<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        let mut buf = [0u8; 64];
+        let mut len = 0;
+        for byte in iter {
+            buf[len] = byte;
+            len += 1;
+            if len == buf.len() {
+                self.update(buf);
+                len = 0;
+            }
+        }
+        self.update(&buf[..len]);
+    }
+
+    /// Absorbs the [`Display`](fmt::Display) representation of `value`, e.g. the decimal digits of
+    /// a number.
+    ///
+    /// The formatted output is streamed into the hasher without building an intermediate `String`.
+    pub fn update_display(&mut self, value: impl fmt::Display) {
+        struct Writer<'a>(&'a mut Sha3);
+
+        impl fmt::Write for Writer<'_> {
+            #[inline]
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0.update(s);
+                Ok(())
+            }
+        }
+
+        // Writing to the hasher never fails, so an error can only come from a `Display` impl that
+        // violates its contract. The output written until then is absorbed regardless.
+        let _ = fmt::Write::write_fmt(&mut Writer(self), format_args!("{value}"));
+    }
+
+    /// Absorbs the domain byte `domain` followed by `bytes`.
+    ///
+    /// Used for tree hashing, where leaves and interior nodes are hashed under different domain
+    /// bytes, see [`merkle_leaf_hash`](crate::merkle_leaf_hash).
+    #[inline]
+    pub fn update_delimited(&mut self, domain: u8, bytes: impl AsRef<[u8]>) {
+        self.update([domain]);
+        self.update(bytes);
+    }
+
+    /// Absorbs `bytes` prefixed with their length as a big-endian `u64`.
+    ///
+    /// Absorbing every field this way makes the encoding of a sequence of variable-length fields
+    /// unambiguous, see [`sha3_tuple!`](crate::sha3_tuple).
+    #[inline]
+    pub fn update_prefixed(&mut self, bytes: impl AsRef<[u8]>) {
+        let bytes = bytes.as_ref();
+        self.update((bytes.len() as u64).to_be_bytes());
+        self.update(bytes);
+    }
+
+    /// Absorbs the bytes encoded by the hex string `hex`, which may be `0x`-prefixed.
+    ///
+    /// The input is validated before anything is absorbed, so the state is unchanged on error. The
+    /// decoded bytes go through a small stack buffer instead of an intermediate allocation.
+    pub fn update_hex(&mut self, hex: &str) -> Result<(), hex::FromHexError> {
+        let hex = hex.strip_prefix("0x").unwrap_or(hex).as_bytes();
+        if !hex.len().is_multiple_of(2) {
+            return Err(hex::FromHexError::OddLength);
+        }
+        if let Some(index) = hex.iter().position(|b| !b.is_ascii_hexdigit()) {
+            return Err(hex::FromHexError::InvalidHexCharacter { c: hex[index] as char, index });
+        }
+
+        let mut buf = [0u8; 64];
+        for chunk in hex.chunks(buf.len() * 2) {
+            let out = &mut buf[..chunk.len() / 2];
+            hex::decode_to_slice(chunk, out)?;
+            self.update(out);
+        }
+        Ok(())
+    }
+
+    /// Absorbs `total_len` as a big-endian `u64`, announcing a body of that many bytes.
+    ///
+    /// This generalizes the length framing of [EIP-191] to binary protocols: absorb the body with
+    /// [`update`](Self::update), in any number of chunks, and finish with
+    /// [`finalize_length_framed`](Self::finalize_length_framed). The length must be the first
+    /// input of the hasher, which is checked in debug builds.
+    ///
+    /// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+    #[inline]
+    #[track_caller]
+    pub fn begin_length_framed(&mut self, total_len: u64) {
+        debug_assert_eq!(self.len, 0, "the frame length must be absorbed first");
+        self.update(total_len.to_be_bytes());
+    }
+
+    /// Pad and squeeze the state of a hasher started with
+    /// [`begin_length_framed`](Self::begin_length_framed).
+    ///
+    /// In debug builds, panics if the number of body bytes absorbed is not `expected_len`, which
+    /// catches a mismatch between the declared and the actual length early.
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn finalize_length_framed(self, expected_len: u64) -> B256 {
+        debug_assert_eq!(
+            self.len.checked_sub(8),
+            Some(expected_len),
+            "absorbed body length does not match the declared length"
+        );
+        self.finalize()
+    }
+
+    /// Returns the digest of the input absorbed so far, without consuming the hasher.
+    ///
+    /// `tiny_keccak` only squeezes by value, so this clones the 200-byte sponge state plus the
+    /// buffered input, and finalizes the clone. This is cheap compared to absorbing a block, but
+    /// not free.
+    ///
+    /// Dropping the digest fails to compile with `unused_must_use` denied:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// core_reth_primitives::Sha3::new().peek();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn peek(&self) -> B256 {
+        self.clone().finalize()
+    }
+
+    /// Absorbs the current digest of `other`, as returned by [`peek`](Self::peek), into `self`.
+    ///
+    /// This links two running hashers, e.g. to build hash chains or accumulators. `other` is left
+    /// unchanged and can keep absorbing input.
+    #[inline]
+    pub fn absorb_digest(&mut self, other: &Self) {
+        self.update(other.peek());
+    }
+
+    /// Pad and squeeze the state, failing if no input bytes were absorbed.
+    ///
+    /// This is opt-in strictness for protocols where hashing empty input indicates a bug, e.g. a
+    /// required field that was left empty. Calls to [`update`](Self::update) with an empty slice do
+    /// not count as input.
+    #[inline]
+    pub fn finalize_nonempty(self) -> Result<B256, EmptyInputError> {
+        if self.len == 0 {
+            return Err(EmptyInputError);
+        }
+        Ok(self.finalize())
+    }
+
     /// Pad and squeeze the state into `output`.
     ///
     /// # Panics
@@ -62,12 +299,37 @@ impl Sha3 {
         self.finalize_into_array(output.try_into().unwrap())
     }
 
+    /// Pad and squeeze the state into `output`.
+    ///
+    /// Returns [`Error::InvalidLength`] instead of panicking if `output` is not 32 bytes long.
+    #[inline]
+    pub fn try_finalize_into(self, output: &mut [u8]) -> Result<(), Error> {
+        let got = output.len();
+        let output = output.try_into().map_err(|_| Error::InvalidLength { expected: 32, got })?;
+        self.finalize_into_array(output);
+        Ok(())
+    }
+
     /// Pad and squeeze the state into `output`.
     #[inline]
     pub fn finalize_into_array(self, output: &mut [u8; 32]) {
         self.hasher.finalize(output);
     }
 
+    /// Pad and squeeze the state into `output`.
+    ///
+    /// `N` must be 32, which is checked at compile time:
+    ///
+    /// ```compile_fail
+    /// let mut output = alloy_primitives::FixedBytes::<20>::ZERO;
+    /// core_reth_primitives::Sha3::new().finalize_into_fixed(&mut output);
+    /// ```
+    #[inline]
+    pub fn finalize_into_fixed<const N: usize>(self, output: &mut FixedBytes<N>) {
+        const { assert!(N == 32, "SHA3-256 digests are 32 bytes") };
+        self.finalize_into_array(output.as_mut_slice().try_into().unwrap());
+    }
+
     /// Pad and squeeze the state into `output`.
     ///
     /// # Safety
@@ -77,13 +339,89 @@ impl Sha3 {
     pub unsafe fn finalize_into_raw(self, output: *mut u8) {
         self.finalize_into_array(&mut *output.cast::<[u8; 32]>())
     }
+
+    /// Pad and squeeze the state, returning only the first `n` bytes of the digest.
+    ///
+    /// This is the truncated SHA3-256 output, not SHAKE. Truncating the output of an approved hash
+    /// function is permitted by NIST SP 800-107, at the cost of reducing the security level to the
+    /// truncated length.
+    ///
+    /// Dropping the truncated digest fails to compile with `unused_must_use` denied:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// core_reth_primitives::Sha3::new().finalize_truncated(4);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than 32.
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn finalize_truncated(self, n: usize) -> Vec<u8> {
+        assert!(n <= 32, "truncated length {n} exceeds the 32-byte digest");
+        self.finalize()[..n].to_vec()
+    }
+
+    /// Pad and squeeze the state, and return the *last* 20 bytes of the digest as an [`Address`].
+    ///
+    /// This is the address scheme of Core: the network independent part of an ICAN address is the
+    /// last 20 bytes of the SHA3-256 hash of the public key, not the first.
+    ///
+    /// Dropping the address fails to compile with `unused_must_use` denied:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// core_reth_primitives::Sha3::new().finalize_address();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn finalize_address(self) -> Address {
+        Address::from_word(self.finalize())
+    }
 }
 
 
 
+/// Hashes a sequence of `AsRef<[u8]>` fields unambiguously.
+///
+/// Each field is absorbed with [`Sha3::update_prefixed`], in order, so `sha3_tuple!(a, b)` differs
+/// from both `sha3_tuple!(b, a)` and the hash of the concatenation. Reordering, adding or removing
+/// fields changes the digest.
+///
+/// ```
+/// use core_reth_primitives::{sha3_tuple, Sha3};
+///
+/// let digest = sha3_tuple!(b"alice", "bob", [1u8, 2]);
+///
+/// let mut hasher = Sha3::new();
+/// hasher.update_prefixed(b"alice");
+/// hasher.update_prefixed("bob");
+/// hasher.update_prefixed([1u8, 2]);
+/// assert_eq!(digest, hasher.finalize());
+/// ```
+#[macro_export]
+macro_rules! sha3_tuple {
+    ($($field:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut hasher = $crate::Sha3::new();
+        $(hasher.update_prefixed($field);)*
+        hasher.finalize()
+    }};
+}
+
 /// Simple interface to the [`Sha3-256`] hash function.
 ///
+/// Dropping the digest fails to compile with `unused_must_use` denied:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// core_reth_primitives::sha3(b"hello world");
+/// ```
+///
 /// [`Sha3`]: https://en.wikipedia.org/wiki/SHA-3
+#[must_use]
 pub fn sha3<T: AsRef<[u8]>>(bytes: T) -> B256 {
     fn sha3(bytes: &[u8]) -> B256 {
         let mut output = MaybeUninit::<B256>::uninit();
@@ -99,11 +437,220 @@ pub fn sha3<T: AsRef<[u8]>>(bytes: T) -> B256 {
     sha3(bytes.as_ref())
 }
 
+/// Same as [`sha3`], but returns the digest as a [`GenericArray`] for RustCrypto-based APIs.
+#[cfg(feature = "digest")]
+#[must_use]
+pub fn sha3_generic(input: &[u8]) -> GenericArray<u8, U32> {
+    sha3(input).0.into()
+}
+
+/// Returns the SHA3-256 digest of `input` together with its length in bytes, e.g. for throughput
+/// metrics.
+#[inline]
+#[must_use]
+pub fn sha3_counted(input: &[u8]) -> (B256, usize) {
+    (sha3(input), input.len())
+}
+
+/// Returns the first `N` bytes of the SHA3-256 digest of `input`, for `N <= 32`.
+///
+/// Only the requested bytes are squeezed, directly into a stack array. Using `N > 32` fails to
+/// compile:
+///
+/// ```compile_fail
+/// let _ = core_reth_primitives::sha3_prefix::<33>(b"hello world");
+/// ```
+///
+/// Neither does dropping the prefix with `unused_must_use` denied:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// core_reth_primitives::sha3_prefix::<4>(b"hello world");
+/// ```
+#[must_use]
+pub fn sha3_prefix<const N: usize>(input: &[u8]) -> [u8; N] {
+    const { assert!(N <= 32, "SHA3-256 digests are 32 bytes") };
+    let mut hasher = tiny_keccak::Sha3::v256();
+    hasher.update(input);
+    let mut output = [0u8; N];
+    hasher.finalize(&mut output);
+    output
+}
+
+/// Simple interface to the legacy [`Keccak-256`] hash function, as used by Ethereum.
+///
+/// Keccak-256 differs from [`sha3`] only in its padding, so the two produce unrelated digests for
+/// the same input.
+///
+/// Dropping the digest fails to compile with `unused_must_use` denied:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// core_reth_primitives::keccak256(b"hello world");
+/// ```
+///
+/// [`Keccak-256`]: https://keccak.team/keccak.html
+#[must_use]
+pub fn keccak256<T: AsRef<[u8]>>(bytes: T) -> B256 {
+    let mut output = B256::ZERO;
+    let mut hasher = tiny_keccak::Keccak::v256();
+    hasher.update(bytes.as_ref());
+    hasher.finalize(&mut output.0);
+    output
+}
+
+/// Computes the SHA3-256 digest and the CRC32 checksum of `input` in a single pass.
+///
+/// The CRC32 is only meant as a cheap pre-check for accidental corruption, e.g. before comparing
+/// digests of stored data. It is not a security primitive: collisions are trivial to construct.
+#[cfg(feature = "crc")]
+pub fn sha3_with_crc(input: &[u8]) -> (B256, u32) {
+    let mut hasher = Sha3::new();
+    let mut crc = crc32fast::Hasher::new();
+    for chunk in input.chunks(4096) {
+        hasher.update(chunk);
+        crc.update(chunk);
+    }
+    (hasher.finalize(), crc.finalize())
+}
+
+/// Copies `src` into the start of `dst` and returns the SHA3-256 digest of `src`.
+///
+/// The data is copied and hashed block by block while it is hot in the cache, instead of hashing
+/// and copying the whole buffer in two passes.
+///
+/// Panics if `dst` is shorter than `src`.
+#[track_caller]
+pub fn sha3_copy(src: &[u8], dst: &mut [u8]) -> B256 {
+    assert!(
+        dst.len() >= src.len(),
+        "destination length {} is shorter than source length {}",
+        dst.len(),
+        src.len()
+    );
+    let mut hasher = Sha3::new();
+    for (src, dst) in src.chunks(4096).zip(dst.chunks_mut(4096)) {
+        let dst = &mut dst[..src.len()];
+        dst.copy_from_slice(src);
+        hasher.update(&*dst);
+    }
+    hasher.finalize()
+}
+
+/// Computes the SHA3-256 digest of the concatenation of `items`, without concatenating them.
+///
+/// Dropping the digest fails to compile with `unused_must_use` denied:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// core_reth_primitives::sha3_iter([b"hello", b"world"]);
+/// ```
+#[must_use]
+pub fn sha3_iter<I>(items: I) -> B256
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    let mut hasher = Sha3::new();
+    for item in items {
+        hasher.update(item);
+    }
+    hasher.finalize()
+}
+
+/// Computes the SHA3-256 digest of the concatenation of `segments`.
+///
+/// This is equal to `sha3(segments.concat())`, but absorbs each segment in place instead of
+/// copying all of them into a temporary buffer first, so it never allocates and touches each byte
+/// once. Empty segments are skipped. Prefer it over [`sha3_iter`] when the segments are already
+/// collected in a slice, as it is not generic and compiled once.
+#[must_use]
+pub fn sha3_slices(segments: &[&[u8]]) -> B256 {
+    let mut hasher = Sha3::new();
+    for segment in segments {
+        hasher.update(segment);
+    }
+    hasher.finalize()
+}
+
+/// Computes the SHA3-256 digest of each of `inputs` independently, in order.
+///
+/// Unlike [`sha3_iter`], which hashes the concatenation of its items into a single digest, this
+/// returns one digest per input. See `sha3_batch_par` (behind the `rayon` feature) for large
+/// batches.
+pub fn sha3_batch<T: AsRef<[u8]>>(inputs: &[T]) -> Vec<B256> {
+    inputs.iter().map(sha3).collect()
+}
+
+/// Computes the SHA3-256 digest of every `window`-byte window of `data`, in order.
+///
+/// Windows start at every byte and overlap. SHA3 cannot be rolled, so each window is hashed
+/// independently. Returns no digests if `data` is shorter than `window`.
+///
+/// Panics if `window` is 0.
+#[track_caller]
+pub fn rolling_digests(data: &[u8], window: usize) -> Vec<B256> {
+    rolling_digests_with_stride(data, window, 1)
+}
+
+/// Same as [`rolling_digests`], but windows start every `stride` bytes.
+///
+/// Consecutive windows overlap if `stride < window` and leave gaps if `stride > window`. A
+/// trailing window shorter than `window` is skipped.
+///
+/// Panics if `window` or `stride` is 0.
+#[track_caller]
+pub fn rolling_digests_with_stride(data: &[u8], window: usize, stride: usize) -> Vec<B256> {
+    assert!(window != 0, "window size must be non-zero");
+    assert!(stride != 0, "stride must be non-zero");
+    data.windows(window).step_by(stride).map(sha3).collect()
+}
+
+/// Parallel version of [`sha3_batch`], hashing the inputs on the rayon thread pool.
+#[cfg(feature = "rayon")]
+pub fn sha3_batch_par<T: AsRef<[u8]> + Sync>(inputs: &[T]) -> Vec<B256> {
+    use rayon::prelude::*;
+
+    inputs.par_iter().map(sha3).collect()
+}
+
+/// Computes the SHA3-256 digest of `input` as four big-endian `u64` limbs.
+///
+/// `limbs[0]` holds the first 8 bytes of the digest and `limbs[3]` the last 8 bytes. Use
+/// [`limbs_to_b256`] to reconstruct the digest.
+///
+/// Dropping the limbs fails to compile with `unused_must_use` denied:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// core_reth_primitives::sha3_limbs(b"hello world");
+/// ```
+#[must_use]
+pub fn sha3_limbs(input: &[u8]) -> [u64; 4] {
+    let digest = sha3(input);
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(digest.chunks_exact(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    limbs
+}
+
+/// Reconstructs a [`B256`] from the big-endian `u64` limbs returned by [`sha3_limbs`].
+pub fn limbs_to_b256(limbs: [u64; 4]) -> B256 {
+    let mut out = B256::ZERO;
+    for (chunk, limb) in out.chunks_exact_mut(8).zip(limbs) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    out
+}
+
 /// Constructs a message according to [EIP-191] (version `0x01`).
 ///
 /// The final message is a UTF-8 string, encoded as follows:
 /// `"\x19Core Signed Message:\n" + message.length + message`
 ///
+/// Only requires `alloc`; the length is formatted with [`itoa`], which is `no_std`.
+///
 /// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
 pub fn eip191_message<T: AsRef<[u8]>>(message: T) -> Vec<u8> {
     fn eip191_message(message: &[u8]) -> Vec<u8> {
@@ -121,15 +668,238 @@ pub fn eip191_message<T: AsRef<[u8]>>(message: T) -> Vec<u8> {
     eip191_message(message.as_ref())
 }
 
+/// Radix of the message length in [`eip191_message_with_options`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Radix {
+    /// Decimal digits, as specified by [EIP-191](https://eips.ethereum.org/EIPS/eip-191).
+    #[default]
+    Decimal,
+    /// Lowercase hex digits without a `0x` prefix, as used by some non-standard signers.
+    Hex,
+}
+
+/// Same as [`eip191_message`], but frames `message` with `prefix` and formats its length in
+/// `len_radix`.
+///
+/// Only use [`Radix::Hex`] to verify signatures of signers that deviate from the spec: for
+/// messages of 10 bytes or more, the hex and decimal framings differ, and so do their hashes.
+pub fn eip191_message_with_options(prefix: &str, message: &[u8], len_radix: Radix) -> Vec<u8> {
+    let len = match len_radix {
+        Radix::Decimal => alloc::format!("{}", message.len()),
+        Radix::Hex => alloc::format!("{:x}", message.len()),
+    };
+    [prefix.as_bytes(), len.as_bytes(), message].concat()
+}
+
+/// Same as [`eip191_message`], but only accepts text.
+///
+/// EIP-191 personal messages are meant to be displayed to the signer, so taking a `&str` rules out
+/// signing binary data as if it were a readable message. See [`is_probably_text`] to check
+/// untrusted bytes.
+#[inline]
+pub fn eip191_message_checked(message: &str) -> Vec<u8> {
+    eip191_message(message)
+}
+
+/// Returns `true` if `bytes` look like displayable text: valid UTF-8 without control characters
+/// other than tab, line feed and carriage return.
+///
+/// This is a heuristic for warning users before signing, not a guarantee that the text renders
+/// unambiguously; e.g. it does not reject bidirectional overrides or confusable characters.
+pub fn is_probably_text(bytes: &[u8]) -> bool {
+    core::str::from_utf8(bytes)
+        .is_ok_and(|s| s.chars().all(|c| !c.is_control() || matches!(c, '\t' | '\n' | '\r')))
+}
+
+/// Parses a message framed by [`eip191_message`], returning the original message.
+///
+/// The parser is strict: the input must start with [`EIP191_PREFIX`], followed by the decimal
+/// length without leading zeros, followed by exactly that many message bytes. Since the message
+/// itself may start with digits, the split between length and message is the unique one for which
+/// the declared length matches the number of remaining bytes.
+pub fn parse_eip191(framed: &[u8]) -> Result<&[u8], ParseEip191Error> {
+    let rest = framed
+        .strip_prefix(EIP191_PREFIX.as_bytes())
+        .ok_or(ParseEip191Error::MissingPrefix)?;
+
+    let digits = rest.iter().take_while(|b| b.is_ascii_digit()).count();
+    let mut len = 0u64;
+    for (i, digit) in rest[..digits].iter().enumerate() {
+        if i == 1 && rest[0] == b'0' {
+            break;
+        }
+        match len.checked_mul(10).and_then(|len| len.checked_add(u64::from(digit - b'0'))) {
+            Some(next) => len = next,
+            None => break,
+        }
+        let message = &rest[i + 1..];
+        if message.len() as u64 == len {
+            return Ok(message);
+        }
+    }
+
+    if digits == 0 {
+        Err(ParseEip191Error::MissingLength)
+    } else {
+        Err(ParseEip191Error::LengthMismatch)
+    }
+}
+
+/// Error returned by [`parse_eip191`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseEip191Error {
+    /// The input does not start with [`EIP191_PREFIX`].
+    MissingPrefix,
+    /// The prefix is not followed by a decimal length.
+    MissingLength,
+    /// No split of the input matches its declared length.
+    LengthMismatch,
+}
+
+impl fmt::Display for ParseEip191Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPrefix => f.write_str("missing EIP-191 prefix"),
+            Self::MissingLength => f.write_str("missing EIP-191 message length"),
+            Self::LengthMismatch => f.write_str("EIP-191 message length does not match"),
+        }
+    }
+}
+
+impl core::error::Error for ParseEip191Error {}
+
+/// Hashes `message` according to [EIP-191], i.e. the [`sha3`] of [`eip191_message`].
+///
+/// The framed message is streamed through an [`Eip191Hasher`] rather than built in memory, so this
+/// does not allocate.
+///
+/// Dropping the hash fails to compile with `unused_must_use` denied:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// core_reth_primitives::eip191_hash_message(b"hello world");
+/// ```
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+#[must_use]
 pub fn eip191_hash_message<T: AsRef<[u8]>>(message: T) -> B256 {
-    sha3(eip191_message(message))
+    eip191_hash_message_with_prefix(EIP191_PREFIX, message)
+}
+
+/// Same as [`eip191_hash_message`], but frames `message` with `prefix` instead of
+/// [`EIP191_PREFIX`], e.g. with [`ETHEREUM_EIP191_PREFIX`] for messages signed by other wallets.
+///
+/// Dropping the hash fails to compile with `unused_must_use` denied:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// use core_reth_primitives::{eip191_hash_message_with_prefix, ETHEREUM_EIP191_PREFIX};
+/// eip191_hash_message_with_prefix(ETHEREUM_EIP191_PREFIX, b"hello world");
+/// ```
+#[must_use]
+pub fn eip191_hash_message_with_prefix<T: AsRef<[u8]>>(prefix: &str, message: T) -> B256 {
+    let message = message.as_ref();
+    let mut hasher = Eip191Hasher::with_prefix(prefix, message.len() as u64);
+    hasher.update(message);
+    hasher.finalize()
+}
+
+/// Frames `message` according to [EIP-191] and hashes it, returning both the framed bytes and the
+/// hash to sign.
+///
+/// This is [`eip191_message`] and [`eip191_hash_message`] in one call, framing the message only
+/// once. Useful where the exact signed bytes are shown to the user alongside the hash.
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+pub fn eip191_prepare(message: &[u8]) -> (Vec<u8>, B256) {
+    let framed = eip191_message(message);
+    let hash = sha3(&framed);
+    (framed, hash)
 }
 
+/// Streaming [EIP-191] hasher for messages whose length is known up front.
+///
+/// The prefix and the decimal length are absorbed on construction, so the message body can be fed
+/// in chunks with [`update`](Self::update). The digest equals [`eip191_hash_message`] over the
+/// whole body only if exactly the declared number of bytes was absorbed, see
+/// [`is_complete`](Self::is_complete).
+///
+/// Check [`is_complete`](Self::is_complete) before trusting the digest of untrusted input: extra
+/// bytes can turn the framing of one message into the framing of another, e.g. a declared length
+/// of `1` followed by `"0" || msg` hashes like a 10-byte `msg` declared with length `10`.
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+#[derive(Clone, Debug)]
+pub struct Eip191Hasher {
+    hasher: Sha3,
+    remaining: u64,
+    overflowed: bool,
+}
+
+impl Eip191Hasher {
+    /// Creates a new hasher for a message of `len` bytes.
+    pub fn new(len: u64) -> Self {
+        Self::with_prefix(EIP191_PREFIX, len)
+    }
+
+    /// Same as [`new`](Self::new), but frames the message with `prefix` instead of
+    /// [`EIP191_PREFIX`].
+    pub fn with_prefix(prefix: &str, len: u64) -> Self {
+        let mut hasher = Sha3::new();
+        hasher.update(prefix);
+        hasher.update(itoa::Buffer::new().format(len));
+        Self { hasher, remaining: len, overflowed: false }
+    }
+
+    /// Absorbs the next chunk of the message body.
+    #[inline]
+    pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        let bytes = bytes.as_ref();
+        match self.remaining.checked_sub(bytes.len() as u64) {
+            Some(remaining) => self.remaining = remaining,
+            None => {
+                self.remaining = 0;
+                self.overflowed = true;
+            }
+        }
+        self.hasher.update(bytes);
+    }
 
+    /// Returns the number of bytes still missing from the declared length.
+    ///
+    /// This is also `0` once more than the declared number of bytes was absorbed, use
+    /// [`is_complete`](Self::is_complete) to tell the two apart.
+    #[inline]
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Returns `true` if exactly the declared number of bytes was absorbed, i.e. neither fewer
+    /// nor more.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.remaining == 0 && !self.overflowed
+    }
+
+    /// Pad and squeeze the state.
+    ///
+    /// Dropping the digest fails to compile with `unused_must_use` denied:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// core_reth_primitives::Eip191Hasher::new(0).finalize();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn finalize(self) -> B256 {
+        self.hasher.finalize()
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::{boxed::Box, string::ToString, vec};
     use alloy_primitives::{b256, try_vec, utils::box_try_new};
 
     // test vector taken from:
@@ -147,6 +917,342 @@ mod tests {
         assert_eq!(eip191_hash_message(msg), hash);
     }
 
+    #[test]
+    fn update_fixed() {
+        let digest = sha3(b"hello world");
+        let address = alloy_primitives::address!("9e7622f3ca71fba1d972fd94a31c3bfbf24e3938");
+
+        let mut hasher = Sha3::new();
+        hasher.update_fixed(&digest);
+        hasher.update_fixed(&address.0);
+        hasher.update_fixed(&crate::B1368::repeat_byte(7));
+        assert_eq!(
+            hasher.finalize(),
+            sha3_slices(&[digest.as_slice(), address.as_slice(), &[7; 1368]])
+        );
+    }
+
+    #[test]
+    fn length_framed() {
+        let body = [0x5au8; 300];
+        let mut hasher = Sha3::new();
+        hasher.begin_length_framed(body.len() as u64);
+        for chunk in body.chunks(64) {
+            hasher.update(chunk);
+        }
+        let expected = sha3([&300u64.to_be_bytes()[..], &body].concat());
+        assert_eq!(hasher.finalize_length_framed(300), expected);
+
+        let mut empty = Sha3::new();
+        empty.begin_length_framed(0);
+        assert_eq!(empty.finalize_length_framed(0), sha3([0u8; 8]));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "does not match the declared length"]
+    fn length_framed_mismatch() {
+        let mut hasher = Sha3::new();
+        hasher.begin_length_framed(4);
+        hasher.update(b"abc");
+        let _ = hasher.finalize_length_framed(4);
+    }
+
+    #[test]
+    fn bytes_absorbed() {
+        let mut hasher = Sha3::new();
+        assert_eq!(hasher.bytes_absorbed(), 0);
+        hasher.update(b"hello");
+        hasher.update(b"");
+        hasher.update_prefixed(b" world");
+        hasher.update_bytes([1, 2, 3]);
+        assert_eq!(hasher.bytes_absorbed(), 5 + 8 + 6 + 3);
+        let _ = hasher.finalize_reset();
+        assert_eq!(hasher.bytes_absorbed(), 0);
+
+        assert_eq!(sha3_counted(b"hello world"), (sha3("hello world"), 11));
+        assert_eq!(sha3_counted(b""), (sha3(""), 0));
+    }
+
+    #[test]
+    fn absorb_digest_chain() {
+        let mut genesis = Sha3::new();
+        genesis.update(b"genesis");
+        let mut link = Sha3::new();
+        link.update(b"block 1");
+        link.absorb_digest(&genesis);
+
+        let manual = sha3([&b"block 1"[..], sha3(b"genesis").as_slice()].concat());
+        assert_eq!(link.finalize(), manual);
+        // The absorbed hasher is untouched.
+        assert_eq!(genesis.finalize(), sha3(b"genesis"));
+    }
+
+    #[test]
+    fn keccak256_vectors() {
+        assert_eq!(
+            keccak256(b""),
+            b256!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+        );
+        assert_eq!(
+            keccak256(b"hello"),
+            b256!("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8")
+        );
+    }
+
+    #[test]
+    fn tuple_framing() {
+        let ab = crate::sha3_tuple!(b"a", b"bc");
+        assert_ne!(ab, crate::sha3_tuple!(b"bc", b"a"));
+        assert_ne!(ab, crate::sha3_tuple!(b"ab", b"c"));
+        assert_ne!(ab, sha3(b"abc"));
+        assert_eq!(crate::sha3_tuple!(), sha3(b""));
+
+        let mut manual = Sha3::new();
+        manual.update([0, 0, 0, 0, 0, 0, 0, 1, b'a']);
+        manual.update([0, 0, 0, 0, 0, 0, 0, 2, b'b', b'c']);
+        assert_eq!(ab, manual.finalize());
+    }
+
+    #[test]
+    #[cfg(feature = "crc")]
+    fn sha3_with_crc_vectors() {
+        assert_eq!(sha3_with_crc(b"123456789"), (sha3(b"123456789"), 0xcbf43926));
+        assert_eq!(sha3_with_crc(b""), (sha3(b""), 0));
+
+        let long: Vec<u8> = (0..=255).cycle().take(10_000).collect();
+        assert_eq!(sha3_with_crc(&long), (sha3(&long), crc32fast::hash(&long)));
+    }
+
+    #[test]
+    fn finalize_address_takes_last_bytes() {
+        let mut hasher = Sha3::new();
+        hasher.update(b"hello world");
+        assert_eq!(
+            hasher.finalize_address(),
+            alloy_primitives::address!("9e7622f3ca71fba1d972fd94a31c3bfbf24e3938")
+        );
+    }
+
+    #[test]
+    fn sha3_copy_copies_and_hashes() {
+        let src: Vec<u8> = (0..=255).cycle().take(10_000).collect();
+        let mut dst = vec![0xffu8; 10_001];
+        assert_eq!(sha3_copy(&src, &mut dst), sha3(&src));
+        assert_eq!(dst[..10_000], src[..]);
+        assert_eq!(dst[10_000], 0xff);
+        assert_eq!(sha3_copy(b"", &mut []), sha3(b""));
+    }
+
+    #[test]
+    #[should_panic = "destination length 2 is shorter than source length 3"]
+    fn sha3_copy_short_destination() {
+        sha3_copy(b"abc", &mut [0; 2]);
+    }
+
+    #[test]
+    fn empty_updates_are_noops() {
+        let mut hasher = Sha3::new();
+        for chunk in [&b""[..], b"hello", b"", b"", b" ", b"world", b""] {
+            hasher.update(chunk);
+        }
+        assert_eq!(hasher.finalize(), sha3(b"hello world"));
+    }
+
+    #[test]
+    fn rolling_windows() {
+        assert_eq!(rolling_digests(b"abcd", 2), [sha3(b"ab"), sha3(b"bc"), sha3(b"cd")]);
+        assert_eq!(rolling_digests_with_stride(b"abcde", 2, 2), [sha3(b"ab"), sha3(b"cd")]);
+        assert_eq!(rolling_digests_with_stride(b"abcdefg", 2, 3), [sha3(b"ab"), sha3(b"de")]);
+        assert_eq!(rolling_digests(b"abcd", 4), [sha3(b"abcd")]);
+        assert!(rolling_digests(b"abc", 4).is_empty());
+    }
+
+    #[test]
+    fn eip191_text() {
+        let msg = "Hello World\nSign in to example.com";
+        assert_eq!(eip191_message_checked(msg), eip191_message(msg.as_bytes()));
+        assert!(is_probably_text(msg.as_bytes()));
+        assert!(is_probably_text("grüße\t👋\r\n".as_bytes()));
+        assert!(is_probably_text(b""));
+        assert!(!is_probably_text(b"\x00\x01binary"));
+        assert!(!is_probably_text(b"\x1b[31mred"));
+        assert!(!is_probably_text(&[0xff, 0xfe]));
+    }
+
+    #[test]
+    #[cfg(feature = "digest")]
+    fn sha3_generic_matches_sha3() {
+        for input in [&b""[..], b"hello world"] {
+            assert_eq!(sha3_generic(input).as_slice(), sha3(input).as_slice());
+        }
+    }
+
+    #[test]
+    fn sha3_prefix_vectors() {
+        assert_eq!(sha3_prefix::<4>(b"hello world"), [0x64, 0x4b, 0xcc, 0x7e]);
+        assert_eq!(sha3_prefix::<32>(b"hello world"), sha3(b"hello world").0);
+        assert_eq!(sha3_prefix::<0>(b"hello world"), []);
+    }
+
+    #[test]
+    fn extend() {
+        let bytes: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        let expected = sha3(&bytes);
+
+        let mut hasher = Sha3::new();
+        hasher.extend(bytes.iter().copied());
+        assert_eq!(hasher.finalize(), expected);
+
+        let mut hasher = Sha3::new();
+        hasher.extend(&bytes);
+        assert_eq!(hasher.finalize(), expected);
+
+        let mut hasher = Sha3::new();
+        hasher.extend(bytes.chunks(7));
+        assert_eq!(hasher.finalize(), expected);
+
+        let mut hasher = Sha3::new();
+        hasher.extend(core::iter::empty::<u8>());
+        assert_eq!(hasher.finalize_nonempty(), Err(EmptyInputError));
+    }
+
+    #[test]
+    fn update_display() {
+        let mut hasher = Sha3::new();
+        hasher.update_display(42u64);
+        assert_eq!(hasher.finalize(), sha3("42"));
+
+        let mut hasher = Sha3::new();
+        hasher.update_display(format_args!("{}-{:x}", "block", 255));
+        hasher.update_display(B256::ZERO);
+        assert_eq!(hasher.finalize(), sha3(alloc::format!("block-ff{}", B256::ZERO)));
+    }
+
+    #[test]
+    fn update_bytes() {
+        for len in [0, 1, 63, 64, 65, 1000] {
+            let mut hasher = Sha3::new();
+            hasher.update_bytes((0..len).map(|i| (i % 251) as u8));
+            let collected: Vec<u8> = (0..len).map(|i| (i % 251) as u8).collect();
+            assert_eq!(hasher.finalize(), sha3(&collected));
+        }
+    }
+
+    #[test]
+    fn eip191_prepare_is_consistent() {
+        let (framed, hash) = eip191_prepare(b"Hello World");
+        assert_eq!(framed, eip191_message(b"Hello World"));
+        assert_eq!(hash, sha3(&framed));
+        assert_eq!(hash, eip191_hash_message(b"Hello World"));
+    }
+
+    #[test]
+    fn eip191_length_radix() {
+        let msg = [b'a'; 300];
+        assert_eq!(
+            eip191_message_with_options(EIP191_PREFIX, &msg, Radix::default()),
+            eip191_message(msg)
+        );
+        let hex = eip191_message_with_options(EIP191_PREFIX, &msg, Radix::Hex);
+        assert_eq!(hex, [EIP191_PREFIX.as_bytes(), &b"12c"[..], &msg[..]].concat());
+        assert_eq!(
+            eip191_message_with_options(ETHEREUM_EIP191_PREFIX, b"Hello World", Radix::Hex),
+            b"\x19Ethereum Signed Message:\nbHello World"
+        );
+    }
+
+    #[test]
+    fn eip191_custom_prefix() {
+        let msg = b"Hello World";
+        assert_eq!(eip191_hash_message_with_prefix(EIP191_PREFIX, msg), eip191_hash_message(msg));
+        assert_eq!(
+            eip191_hash_message_with_prefix(ETHEREUM_EIP191_PREFIX, msg),
+            sha3(b"\x19Ethereum Signed Message:\n11Hello World")
+        );
+    }
+
+    #[test]
+    fn eip191_hasher_chunks() {
+        let msg = b"Hello World";
+        let mut hasher = Eip191Hasher::new(msg.len() as u64);
+        for chunk in msg.chunks(3) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hasher.remaining(), 0);
+        assert!(hasher.is_complete());
+        assert_eq!(hasher.finalize(), eip191_hash_message(msg));
+
+        let mut hasher = Eip191Hasher::new(msg.len() as u64);
+        hasher.update(&msg[..5]);
+        assert_eq!(hasher.remaining(), 6);
+        assert!(!hasher.is_complete());
+    }
+
+    #[test]
+    fn eip191_hasher_over_length() {
+        let msg = b"Hello World";
+        let mut hasher = Eip191Hasher::new(msg.len() as u64);
+        hasher.update(msg);
+        hasher.update(b"!");
+        assert_eq!(hasher.remaining(), 0);
+        assert!(!hasher.is_complete());
+
+        // Declaring `1` and absorbing `"1" || msg` frames `msg` with its real length `11`.
+        let mut hasher = Eip191Hasher::new(1);
+        hasher.update(b"1");
+        assert!(hasher.is_complete());
+        hasher.update(msg);
+        assert!(!hasher.is_complete());
+        assert_eq!(hasher.finalize(), eip191_hash_message(msg));
+    }
+
+    #[test]
+    fn parse_eip191_roundtrip() {
+        let long = [b'7'; 1234];
+        let messages: [&[u8]; 7] = [b"", b"0", b"1", b"Hello World", b"123abc", b"10", &long];
+        for msg in messages {
+            assert_eq!(parse_eip191(&eip191_message(msg)), Ok(msg));
+        }
+    }
+
+    #[test]
+    fn parse_eip191_malformed() {
+        let framed = |rest: &[u8]| [EIP191_PREFIX.as_bytes(), rest].concat();
+        assert_eq!(parse_eip191(b""), Err(ParseEip191Error::MissingPrefix));
+        assert_eq!(
+            parse_eip191(b"\x19Ethereum Signed Message:\n0"),
+            Err(ParseEip191Error::MissingPrefix)
+        );
+        assert_eq!(parse_eip191(&framed(b"")), Err(ParseEip191Error::MissingLength));
+        assert_eq!(parse_eip191(&framed(b"abc")), Err(ParseEip191Error::MissingLength));
+        assert_eq!(parse_eip191(&framed(b"5abc")), Err(ParseEip191Error::LengthMismatch));
+        assert_eq!(parse_eip191(&framed(b"2abc")), Err(ParseEip191Error::LengthMismatch));
+        // Leading zeros are not allowed.
+        assert_eq!(parse_eip191(&framed(b"03abc")), Err(ParseEip191Error::LengthMismatch));
+        // Lengths overflowing `u64` are rejected without panicking.
+        assert_eq!(
+            parse_eip191(&framed(b"99999999999999999999999")),
+            Err(ParseEip191Error::LengthMismatch)
+        );
+    }
+
+    #[test]
+    fn slices_match_concat() {
+        let long = [0xab; 300];
+        let cases: [&[&[u8]]; 5] = [
+            &[],
+            &[b""],
+            &[b"hello", b" ", b"world"],
+            &[b"", &long, b"", b"x"],
+            &[&long[..136], &long[136..]],
+        ];
+        for segments in cases {
+            assert_eq!(sha3_slices(segments), sha3(segments.concat()));
+            assert_eq!(sha3_slices(segments), sha3_iter(segments));
+        }
+    }
+
     #[test]
     fn sha3_hasher() {
         let expected = b256!("644bcc7e564373040999aac89e7622f3ca71fba1d972fd94a31c3bfbf24e3938");
@@ -166,11 +1272,119 @@ mod tests {
         hasher.clone().finalize_into_array(&mut hash);
         assert_eq!(hash, expected);
 
+        let mut hash = B256::ZERO;
+        hasher.clone().finalize_into_fixed(&mut hash);
+        assert_eq!(hash, expected);
+
+        let mut hash = [0u8; 32];
+        hasher.clone().try_finalize_into(&mut hash).unwrap();
+        assert_eq!(hash, expected);
+
+        let mut short = [0u8; 31];
+        assert!(matches!(
+            hasher.clone().try_finalize_into(&mut short),
+            Err(Error::InvalidLength { expected: 32, got: 31 })
+        ));
+
         let mut hash = [0u8; 32];
         unsafe { hasher.finalize_into_raw(hash.as_mut_ptr()) };
         assert_eq!(hash, expected);
     }
 
+    #[test]
+    fn update_hex() {
+        let encoded = hex::encode(b"hello world");
+        let mut hasher = Sha3::new();
+        hasher.update_hex(&encoded).unwrap();
+        assert_eq!(hasher.finalize(), sha3("hello world"));
+
+        let long = [0xabu8; 300];
+        let mut hasher = Sha3::new();
+        hasher.update_hex(&hex::encode_prefixed(long)).unwrap();
+        hasher.update_hex("").unwrap();
+        assert_eq!(hasher.finalize(), sha3(long));
+    }
+
+    #[test]
+    fn update_hex_invalid() {
+        let mut hasher = Sha3::new();
+        hasher.update(b"hello");
+        assert_eq!(hasher.update_hex("0xabc"), Err(hex::FromHexError::OddLength));
+        assert_eq!(
+            hasher.update_hex("abzz"),
+            Err(hex::FromHexError::InvalidHexCharacter { c: 'z', index: 2 })
+        );
+        // The state is untouched by failed updates.
+        assert_eq!(hasher.finalize(), sha3("hello"));
+    }
+
+    #[test]
+    fn peek() {
+        let mut hasher = Sha3::new();
+        assert_eq!(hasher.peek(), sha3(""));
+        hasher.update(b"hello");
+        assert_eq!(hasher.peek(), sha3("hello"));
+        hasher.update(b" world");
+        assert_eq!(hasher.peek(), sha3("hello world"));
+        assert_eq!(hasher.finalize(), sha3("hello world"));
+    }
+
+    #[test]
+    fn finalize_nonempty() {
+        assert_eq!(Sha3::new().finalize_nonempty(), Err(EmptyInputError));
+
+        let mut hasher = Sha3::new();
+        hasher.update(b"");
+        assert_eq!(hasher.clone().finalize_nonempty(), Err(EmptyInputError));
+
+        hasher.update(b"hello world");
+        hasher.update(b"");
+        assert_eq!(hasher.finalize_nonempty(), Ok(sha3("hello world")));
+    }
+
+    #[test]
+    fn finalize_truncated() {
+        let expected = sha3("hello world");
+        for n in [0, 1, 4, 20, 32] {
+            let mut hasher = Sha3::new();
+            hasher.update(b"hello world");
+            assert_eq!(hasher.finalize_truncated(n), expected[..n]);
+        }
+    }
+
+    #[test]
+    #[should_panic = "exceeds the 32-byte digest"]
+    fn finalize_truncated_too_long() {
+        let _ = Sha3::new().finalize_truncated(33);
+    }
+
+    #[test]
+    fn sha3_iter_concat() {
+        assert_eq!(sha3_iter(["hello", " ", "world"]), sha3("hello world"));
+        assert_eq!(sha3_iter(Vec::<&[u8]>::new()), sha3(""));
+    }
+
+    #[test]
+    fn sha3_batch_matches_sha3() {
+        let inputs = ["", "hello", "hello world"];
+        let expected: Vec<B256> = inputs.iter().map(sha3).collect();
+        assert_eq!(sha3_batch(&inputs), expected);
+        #[cfg(feature = "rayon")]
+        assert_eq!(sha3_batch_par(&inputs), expected);
+        assert!(sha3_batch::<&[u8]>(&[]).is_empty());
+    }
+
+    #[test]
+    fn sha3_limbs_roundtrip() {
+        let expected = b256!("644bcc7e564373040999aac89e7622f3ca71fba1d972fd94a31c3bfbf24e3938");
+        let limbs = sha3_limbs(b"hello world");
+        assert_eq!(
+            limbs,
+            [0x644bcc7e56437304, 0x0999aac89e7622f3, 0xca71fba1d972fd94, 0xa31c3bfbf24e3938]
+        );
+        assert_eq!(limbs_to_b256(limbs), expected);
+    }
+
     #[test]
     fn test_try_boxing() {
         let x = Box::new(42);
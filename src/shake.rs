@@ -0,0 +1,286 @@
+use tiny_keccak::{Hasher as _, Xof as _};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+/// Simple [`SHAKE256`] extendable-output hasher.
+///
+/// Input is absorbed with [`update`](Self::update) and output of any length is read with
+/// [`squeeze`](Self::squeeze). Consecutive squeezes continue the same output stream, so squeezing
+/// 16 bytes twice yields the same bytes as squeezing 32 at once.
+///
+/// [`SHAKE256`]: https://en.wikipedia.org/wiki/SHA-3
+#[derive(Clone)]
+pub struct Shake {
+    hasher: tiny_keccak::Shake,
+    squeezing: bool,
+}
+
+impl Default for Shake {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Shake {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shake").field("squeezing", &self.squeezing).finish_non_exhaustive()
+    }
+}
+
+impl Shake {
+    /// Creates a new [`Shake`] hasher.
+    #[inline]
+    pub fn new() -> Self {
+        Self { hasher: tiny_keccak::Shake::v256(), squeezing: false }
+    }
+
+    /// Absorbs additional input. Can be called multiple times, but not after squeezing.
+    ///
+    /// Panics if output was already squeezed.
+    #[inline]
+    #[track_caller]
+    pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        assert!(!self.squeezing, "cannot absorb input after squeezing");
+        self.hasher.update(bytes.as_ref());
+    }
+
+    /// Fills `output` with the next bytes of the output stream.
+    #[inline]
+    pub fn squeeze(&mut self, output: &mut [u8]) {
+        self.squeezing = true;
+        self.hasher.squeeze(output);
+    }
+
+    /// Returns the next `N` bytes of the output stream.
+    #[inline]
+    pub fn squeeze_block<const N: usize>(&mut self) -> [u8; N] {
+        let mut block = [0u8; N];
+        self.squeeze(&mut block);
+        block
+    }
+
+    /// Returns an endless iterator over successive `N`-byte blocks of the output stream.
+    ///
+    /// The blocks continue the stream, so `N`-byte blocks taken from it are the same bytes as one
+    /// long [`squeeze`](Self::squeeze), and squeezing afterwards continues after the last block.
+    ///
+    /// ```
+    /// let mut shake = core_reth_primitives::Shake::new();
+    /// shake.update(b"master secret");
+    /// let keys: Vec<[u8; 32]> = shake.blocks::<32>().take(3).collect();
+    /// ```
+    #[inline]
+    pub fn blocks<const N: usize>(&mut self) -> ShakeBlocks<'_, N> {
+        ShakeBlocks { shake: self }
+    }
+}
+
+/// Iterator over `N`-byte blocks of a [`Shake`] output stream, see [`Shake::blocks`].
+#[derive(Debug)]
+pub struct ShakeBlocks<'a, const N: usize> {
+    shake: &'a mut Shake,
+}
+
+impl<const N: usize> Iterator for ShakeBlocks<'_, N> {
+    type Item = [u8; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.shake.squeeze_block())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<const N: usize> core::iter::FusedIterator for ShakeBlocks<'_, N> {}
+
+#[cfg(feature = "std")]
+impl io::Write for Shake {
+    /// Absorbs `buf`. Panics if output was already squeezed, see [`Shake::update`].
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "digest")]
+mod digest_impl {
+    use super::Shake;
+    use digest::{ExtendableOutput, Update, XofReader};
+
+    impl Update for Shake {
+        #[inline]
+        fn update(&mut self, data: &[u8]) {
+            Shake::update(self, data);
+        }
+    }
+
+    impl ExtendableOutput for Shake {
+        /// The output stream is read from the same [`Shake`], see [`Shake::squeeze`].
+        type Reader = Self;
+
+        #[inline]
+        fn finalize_xof(self) -> Self {
+            self
+        }
+    }
+
+    impl XofReader for Shake {
+        #[inline]
+        fn read(&mut self, buffer: &mut [u8]) {
+            self.squeeze(buffer);
+        }
+    }
+}
+
+/// A [`Shake`] that counts the bytes squeezed so far.
+///
+/// Useful to enforce an output budget, e.g. in key derivation.
+#[derive(Clone, Debug, Default)]
+pub struct CountingShake {
+    shake: Shake,
+    squeezed: u64,
+}
+
+impl CountingShake {
+    /// Creates a new [`CountingShake`] hasher.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs additional input, see [`Shake::update`].
+    #[inline]
+    #[track_caller]
+    pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        self.shake.update(bytes);
+    }
+
+    /// Fills `output` with the next bytes of the output stream, see [`Shake::squeeze`].
+    #[inline]
+    pub fn squeeze(&mut self, output: &mut [u8]) {
+        self.shake.squeeze(output);
+        self.squeezed += output.len() as u64;
+    }
+
+    /// Returns the number of bytes squeezed so far.
+    #[inline]
+    pub fn squeezed(&self) -> u64 {
+        self.squeezed
+    }
+
+    /// Unwraps the inner [`Shake`].
+    #[inline]
+    pub fn into_inner(self) -> Shake {
+        self.shake
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use alloy_primitives::hex;
+
+    #[test]
+    fn shake256_vector() {
+        let mut out = [0u8; 32];
+        Shake::new().squeeze(&mut out);
+        assert_eq!(out, hex!("46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f"));
+    }
+
+    #[test]
+    fn squeeze_continues_stream() {
+        let mut shake = Shake::new();
+        shake.update(b"hello");
+        let mut whole = [0u8; 300];
+        shake.clone().squeeze(&mut whole);
+
+        let mut parts = [0u8; 300];
+        let (a, b) = parts.split_at_mut(7);
+        shake.squeeze(a);
+        shake.squeeze(b);
+        assert_eq!(whole, parts);
+    }
+
+    #[test]
+    fn blocks_continue_stream() {
+        let mut shake = Shake::new();
+        shake.update(b"key schedule");
+        let mut whole = [0u8; 32 * 5 + 8];
+        shake.clone().squeeze(&mut whole);
+
+        let blocks: Vec<[u8; 32]> = shake.blocks().take(4).collect();
+        let block: [u8; 32] = shake.squeeze_block();
+        let mut tail = [0u8; 8];
+        shake.squeeze(&mut tail);
+        assert_eq!(blocks.concat(), whole[..128]);
+        assert_eq!(block, whole[128..160]);
+        assert_eq!(tail, whole[160..]);
+    }
+
+    #[test]
+    #[should_panic = "cannot absorb input after squeezing"]
+    fn update_after_squeeze() {
+        let mut shake = Shake::new();
+        shake.squeeze(&mut [0u8; 1]);
+        shake.update(b"late");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn write() {
+        let mut expected = Shake::new();
+        expected.update(b"hello world");
+        let mut shake = Shake::new();
+        io::copy(&mut &b"hello world"[..], &mut shake).unwrap();
+
+        let (mut a, mut b) = ([0u8; 64], [0u8; 64]);
+        expected.squeeze(&mut a);
+        shake.squeeze(&mut b);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    #[cfg(feature = "digest")]
+    fn xof_trait_matches_native() {
+        use digest::{ExtendableOutput, Update, XofReader};
+
+        let mut native = Shake::new();
+        native.update(b"hello world");
+        let mut expected = [0u8; 300];
+        native.squeeze(&mut expected);
+
+        let mut shake = Shake::new();
+        Update::update(&mut shake, b"hello ");
+        Update::update(&mut shake, b"world");
+        let mut reader = shake.finalize_xof();
+        let mut out = [0u8; 300];
+        let (a, b) = out.split_at_mut(100);
+        reader.read(a);
+        reader.read(b);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn counting() {
+        let mut shake = CountingShake::new();
+        shake.update(b"seed");
+        assert_eq!(shake.squeezed(), 0);
+        shake.squeeze(&mut [0u8; 32]);
+        shake.squeeze(&mut [0u8; 10]);
+        assert_eq!(shake.squeezed(), 42);
+    }
+}
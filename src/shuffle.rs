@@ -0,0 +1,104 @@
+use crate::{sha3, Shake};
+use alloy_primitives::{B256, U256};
+
+/// Shuffles `items` in place with a Fisher-Yates shuffle driven by the [`Shake`] output stream
+/// of `seed`.
+///
+/// The permutation only depends on `seed` and the number of items, so it is the same on every
+/// platform and in every run. Each step draws a big-endian `u64` from the stream and reduces it
+/// modulo the number of remaining items.
+///
+/// This is meant for reproducibility, e.g. for test vectors and fair-ordering protocols, not for
+/// cryptographic uniformity: the modulo reduction has a bias of at most `len / 2^64`, which is
+/// negligible but not zero. Anyone who knows the seed can compute the permutation.
+pub fn shuffle_deterministic<T>(items: &mut [T], seed: B256) {
+    let mut shake = Shake::new();
+    shake.update(seed);
+    for i in (1..items.len()).rev() {
+        let mut word = [0u8; 8];
+        shake.squeeze(&mut word);
+        let j = u64::from_be_bytes(word) % (i as u64 + 1);
+        items.swap(i, j as usize);
+    }
+}
+
+/// Selects an index of `weights` with probability proportional to its weight, deterministically
+/// from `seed`, e.g. a block hash for stake-weighted leader election.
+///
+/// `sha3(seed)` is read as a big-endian 256-bit integer and reduced modulo the total weight, and
+/// the index whose cumulative weight interval `[start, start + weight)` contains the result is
+/// returned. Indices with a zero weight are never selected. The modulo bias is at most
+/// `total / 2^256`.
+///
+/// Returns `None` if `weights` is empty or all weights are zero.
+pub fn weighted_select(seed: B256, weights: &[u64]) -> Option<usize> {
+    let total: u128 = weights.iter().map(|&weight| u128::from(weight)).sum();
+    if total == 0 {
+        return None;
+    }
+    let value = U256::from_be_bytes(sha3(seed).0) % U256::from(total);
+    let mut target = value.to::<u128>();
+    weights.iter().position(|&weight| {
+        let weight = u128::from(weight);
+        if target < weight {
+            return true;
+        }
+        target -= weight;
+        false
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn stable_permutation() {
+        let mut items: Vec<u32> = (0..10).collect();
+        shuffle_deterministic(&mut items, B256::repeat_byte(0x42));
+        assert_eq!(items, [1, 4, 9, 5, 2, 6, 3, 8, 0, 7]);
+    }
+
+    #[test]
+    fn permutation_depends_on_seed() {
+        let original: Vec<u32> = (0..100).collect();
+        let mut a = original.clone();
+        let mut b = original.clone();
+        shuffle_deterministic(&mut a, B256::repeat_byte(1));
+        shuffle_deterministic(&mut b, B256::repeat_byte(2));
+        assert_ne!(a, b);
+        assert_ne!(a, original);
+
+        let mut sorted = a.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, original);
+
+        let mut again = original.clone();
+        shuffle_deterministic(&mut again, B256::repeat_byte(1));
+        assert_eq!(again, a);
+
+        let mut empty: [u32; 0] = [];
+        shuffle_deterministic(&mut empty, B256::ZERO);
+        let mut single = [7];
+        shuffle_deterministic(&mut single, B256::ZERO);
+        assert_eq!(single, [7]);
+    }
+
+    #[test]
+    fn weighted_selection() {
+        // Cumulative intervals: [0, 10), [10, 10), [10, 40), [40, 100).
+        let weights = [10, 0, 30, 60];
+        assert_eq!(weighted_select(B256::repeat_byte(0x42), &weights), Some(0));
+        assert_eq!(weighted_select(B256::repeat_byte(2), &weights), Some(3));
+        assert_eq!(weighted_select(B256::repeat_byte(5), &weights), Some(2));
+        for byte in 0..=255 {
+            let seed = B256::repeat_byte(byte);
+            assert_ne!(weighted_select(seed, &weights), Some(1));
+            assert_eq!(weighted_select(seed, &[0, 0, u64::MAX]), Some(2));
+        }
+
+        assert_eq!(weighted_select(B256::ZERO, &[]), None);
+        assert_eq!(weighted_select(B256::ZERO, &[0, 0]), None);
+    }
+}
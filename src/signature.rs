@@ -0,0 +1,695 @@
+use crate::{
+    eip191_hash_message, eip191_hash_message_with_prefix, Eip191Hasher, Signature, SignatureError,
+};
+use alloc::{collections::BTreeMap, format, string::String};
+use alloy_primitives::{hex, Address, B256};
+use base64::Engine as _;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
+/// Length in bytes of an encoded Core signature: the 114-byte ed448 signature followed by the
+/// 57-byte public key of the signer.
+pub const SIGNATURE_LENGTH: usize = 171;
+
+/// Textual encoding of a [`Signature`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignatureEncoding {
+    /// `0x`- or `0X`-prefixed hex.
+    Hex,
+    /// Standard, padded base64.
+    Base64,
+}
+
+impl SignatureEncoding {
+    /// Detects the encoding of `s`: `0x`- or `0X`-prefixed strings are hex, everything else is
+    /// base64.
+    #[inline]
+    pub fn detect(s: &str) -> Self {
+        if s.starts_with("0x") || s.starts_with("0X") {
+            Self::Hex
+        } else {
+            Self::Base64
+        }
+    }
+}
+
+/// Decodes a [`Signature`] from `s` using the given encoding.
+///
+/// Core signatures have a single layout of exactly [`SIGNATURE_LENGTH`] bytes. There are no
+/// 64-byte compact (EIP-2098) or 65-byte `r || s || v` forms to detect, and no recovery id to
+/// normalize.
+pub fn decode_signature(s: &str, encoding: SignatureEncoding) -> Result<Signature, SignatureError> {
+    let bytes = match encoding {
+        SignatureEncoding::Hex => {
+            let s = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")).unwrap_or(s);
+            hex::decode(s).map_err(|_| SignatureError::FromBytes("invalid hex signature"))?
+        }
+        SignatureEncoding::Base64 => base64::engine::general_purpose::STANDARD
+            .decode(s)
+            .map_err(|_| SignatureError::FromBytes("invalid base64 signature"))?,
+    };
+    if bytes.len() != SIGNATURE_LENGTH {
+        return Err(SignatureError::FromBytes("expected exactly 171 signature bytes"));
+    }
+    Signature::try_from(bytes.as_slice())
+}
+
+/// Recovers the signer of `prehash`.
+///
+/// Core addresses are ICAN encoded, i.e. a two-byte network prefix and checksum followed by the
+/// last 20 bytes of the SHA3-256 hash of the public key. The helpers in this crate work with the
+/// network independent 20-byte part only.
+///
+/// Unlike secp256k1 signatures, Core signatures carry no recovery id (`v`) that would need to be
+/// normalized across legacy, `0`/`1` and EIP-155 encodings: the signer's public key is part of
+/// the signature itself.
+///
+/// Recovery always goes through the ed448 implementation of `base-primitives`. There is no
+/// secp256k1 curve involved, so there is no `k256` or `libsecp256k1` backend to select.
+///
+/// This does not allocate, and neither does [`recover_address_from_msg`]. The heap is only used by
+/// helpers that return owned buffers, such as [`eip191_message`](crate::eip191_message).
+pub fn recover_address_from_prehash(
+    prehash: &B256,
+    sig: &Signature,
+) -> Result<Address, SignatureError> {
+    let ican = sig.recover_address_from_prehash(&base_primitives::B256::new(prehash.0))?;
+    Ok(Address::from_slice(&ican[2..]))
+}
+
+/// A caller-owned memo of recovered signers, keyed by `(prehash, signature)`.
+///
+/// Scope one memo to a unit of work, e.g. a request lifecycle, so retries of the same recovery
+/// skip the curve operation. The memo is unbounded and not shared; see `VerificationCache`
+/// (behind the `lru` feature) for a bounded cache shared between threads. Failed recoveries are
+/// not memoized.
+#[derive(Clone, Debug, Default)]
+pub struct RecoveryMemo {
+    signers: BTreeMap<(B256, [u8; SIGNATURE_LENGTH]), Address>,
+}
+
+impl RecoveryMemo {
+    /// Creates an empty memo.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Same as [`recover_address_from_prehash`], but returns the memoized signer on repeat calls.
+    pub fn recover_memoized(
+        &mut self,
+        prehash: B256,
+        sig: &Signature,
+    ) -> Result<Address, SignatureError> {
+        self.recover_with(prehash, sig, recover_address_from_prehash)
+    }
+
+    fn recover_with(
+        &mut self,
+        prehash: B256,
+        sig: &Signature,
+        recover: impl FnOnce(&B256, &Signature) -> Result<Address, SignatureError>,
+    ) -> Result<Address, SignatureError> {
+        let key = (prehash, sig.as_bytes());
+        if let Some(signer) = self.signers.get(&key) {
+            return Ok(*signer);
+        }
+        let signer = recover(&prehash, sig)?;
+        self.signers.insert(key, signer);
+        Ok(signer)
+    }
+
+    /// Returns the number of memoized signers.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.signers.len()
+    }
+
+    /// Returns `true` if no signers are memoized.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.signers.is_empty()
+    }
+}
+
+/// Recovers the signer of the [EIP-191] message `msg`.
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+pub fn recover_address_from_msg<T: AsRef<[u8]>>(
+    msg: T,
+    sig: &Signature,
+) -> Result<Address, SignatureError> {
+    recover_address_from_prehash(&eip191_hash_message(msg), sig)
+}
+
+/// Returns the login envelope signed by [`recover_login`]:
+///
+/// `Sign in to {app} at {timestamp}, nonce {nonce}`
+///
+/// The timestamp is formatted in decimal and the nonce as `0x`-prefixed lowercase hex, e.g.
+/// `Sign in to example.com at 1700000000, nonce 0x4242..42`. Frontends must produce this exact
+/// string, byte for byte, and sign it as an [EIP-191] personal message.
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+pub fn login_message(app: &str, timestamp: u64, nonce: &B256) -> String {
+    format!("Sign in to {app} at {timestamp}, nonce {nonce}")
+}
+
+/// Recovers the signer of the [`login_message`] for `app`, `timestamp` and `nonce`, hashed with
+/// [`EIP191_PREFIX`](crate::EIP191_PREFIX).
+///
+/// Checking that the timestamp is recent and that the nonce was issued and not used before is up
+/// to the caller.
+pub fn recover_login(
+    app: &str,
+    timestamp: u64,
+    nonce: &B256,
+    sig: &Signature,
+) -> Result<Address, SignatureError> {
+    recover_address_from_msg(login_message(app, timestamp, nonce), sig)
+}
+
+/// Recovers the signer of `msg` framed with any of `prefixes`, for wallets that sign with
+/// different [EIP-191] prefixes.
+///
+/// The prefixes are tried in order. Returns the signer and the index of the first prefix the
+/// signature is valid for, or `None` if it is valid for none of them.
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+pub fn recover_eip191_any_prefix(
+    msg: &[u8],
+    sig: &Signature,
+    prefixes: &[&str],
+) -> Option<(Address, usize)> {
+    prefixes.iter().enumerate().find_map(|(i, prefix)| {
+        let prehash = eip191_hash_message_with_prefix(prefix, msg);
+        recover_address_from_prehash(&prehash, sig).ok().map(|signer| (signer, i))
+    })
+}
+
+/// Verifies that `sig` is a signature by `expected` over the [EIP-191] message `msg`.
+///
+/// Returns `Ok(false)` if the signature is valid but was made by a different signer, and an error
+/// if no signer can be recovered at all.
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+pub fn verify_eip191(
+    msg: &[u8],
+    sig: &Signature,
+    expected: Address,
+) -> Result<bool, SignatureError> {
+    recover_address_from_msg(msg, sig).map(|signer| signer == expected)
+}
+
+/// Returns the first address in `candidates` whose paired signature is a valid signature by it
+/// over the [EIP-191] message `msg`, or `None` if there is none.
+///
+/// This answers whether `msg` is authorized by anyone on the list; the message is hashed once for
+/// all candidates. Signatures that fail to recover, or recover to a different signer than their
+/// paired address, are skipped.
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+pub fn verify_eip191_any(msg: &[u8], candidates: &[(Signature, Address)]) -> Option<Address> {
+    verify_any_with(&eip191_hash_message(msg), candidates, recover_address_from_prehash)
+}
+
+fn verify_any_with(
+    prehash: &B256,
+    candidates: &[(Signature, Address)],
+    recover: impl Fn(&B256, &Signature) -> Result<Address, SignatureError>,
+) -> Option<Address> {
+    candidates
+        .iter()
+        .find(|(sig, address)| recover(prehash, sig).is_ok_and(|signer| signer == *address))
+        .map(|&(_, address)| address)
+}
+
+/// Error returned by [`recover_and_verify_eip191`].
+#[derive(Debug)]
+pub enum VerificationError {
+    /// The signature is valid, but was made by a different signer.
+    WrongSigner {
+        /// The expected signer.
+        expected: Address,
+        /// The signer recovered from the signature.
+        recovered: Address,
+    },
+    /// No signer could be recovered from the signature.
+    Signature(SignatureError),
+}
+
+impl fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongSigner { expected, recovered } => {
+                write!(f, "wrong signer: expected {expected}, recovered {recovered}")
+            }
+            Self::Signature(err) => write!(f, "signature error: {err}"),
+        }
+    }
+}
+
+impl core::error::Error for VerificationError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Signature(err) => Some(err),
+            Self::WrongSigner { .. } => None,
+        }
+    }
+}
+
+impl From<SignatureError> for VerificationError {
+    #[inline]
+    fn from(err: SignatureError) -> Self {
+        Self::Signature(err)
+    }
+}
+
+/// Same as [`verify_eip191`], but returns the recovered signer on success.
+///
+/// Unlike [`verify_eip191`], a valid signature by a different signer is an error, distinct from a
+/// signature that cannot be recovered at all.
+pub fn recover_and_verify_eip191(
+    msg: &[u8],
+    sig: &Signature,
+    expected: Address,
+) -> Result<Address, VerificationError> {
+    let recovered = recover_address_from_msg(msg, sig)?;
+    if recovered != expected {
+        return Err(VerificationError::WrongSigner { expected, recovered });
+    }
+    Ok(recovered)
+}
+
+/// Incrementally verifies an [EIP-191] message whose signature only arrives after the payload.
+///
+/// The total payload length must be known up front, as it is part of the EIP-191 framing. Feed the
+/// payload with [`update`](Self::update), then check the trailing signature with
+/// [`finalize_verify`](Self::finalize_verify). The payload is never buffered.
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+#[derive(Clone, Debug)]
+pub struct StreamingVerifier {
+    hasher: Eip191Hasher,
+}
+
+impl StreamingVerifier {
+    /// Creates a new verifier for a payload of `len` bytes.
+    #[inline]
+    pub fn new(len: u64) -> Self {
+        Self { hasher: Eip191Hasher::new(len) }
+    }
+
+    /// Absorbs the next chunk of the payload.
+    #[inline]
+    pub fn update(&mut self, chunk: impl AsRef<[u8]>) {
+        self.hasher.update(chunk);
+    }
+
+    /// Returns the number of payload bytes still missing from the declared length.
+    ///
+    /// This is also `0` once more than the declared number of bytes was absorbed, see
+    /// [`Eip191Hasher::is_complete`].
+    #[inline]
+    pub fn remaining(&self) -> u64 {
+        self.hasher.remaining()
+    }
+
+    /// Returns `true` if exactly the declared number of bytes was absorbed and `sig` is a
+    /// signature by `expected` over the payload.
+    ///
+    /// A payload longer than declared is rejected even if the signature matches the absorbed
+    /// bytes, since extra bytes can re-frame them as a different, signed message.
+    pub fn finalize_verify(self, sig: &Signature, expected: Address) -> bool {
+        self.hasher.is_complete()
+            && recover_address_from_prehash(&self.hasher.finalize(), sig)
+                .is_ok_and(|signer| signer == expected)
+    }
+}
+
+/// Same as [`verify_eip191`], but decodes the signature from a `0x`-prefixed hex or base64 string
+/// first. The encoding is detected with [`SignatureEncoding::detect`].
+pub fn verify_eip191_str(
+    msg: &[u8],
+    sig_str: &str,
+    expected: Address,
+) -> Result<bool, SignatureError> {
+    let sig = decode_signature(sig_str, SignatureEncoding::detect(sig_str))?;
+    verify_eip191(msg, &sig, expected)
+}
+
+/// Verifies that `sig` is a signature by `expected` over the [EIP-191] message read from `reader`.
+///
+/// Exactly `len` bytes are streamed from `reader` through an [`Eip191Hasher`], so the message is
+/// never buffered. Fails with [`io::ErrorKind::UnexpectedEof`] if the reader ends early, and with
+/// [`io::ErrorKind::InvalidData`] if no signer can be recovered.
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+#[cfg(feature = "std")]
+pub fn verify_eip191_reader<R: Read>(
+    reader: &mut R,
+    len: u64,
+    sig: &Signature,
+    expected: Address,
+) -> io::Result<bool> {
+    let mut hasher = Eip191Hasher::new(len);
+    let mut reader = reader.take(len);
+    let mut buf = [0u8; 8192];
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        hasher.update(&buf[..n]);
+    }
+    if hasher.remaining() != 0 {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    recover_address_from_prehash(&hasher.finalize(), sig)
+        .map(|signer| signer == expected)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// Real signatures shared by the signature tests.
+///
+/// The signatures were made with the RFC 8032 Ed448 implementation of Python's `cryptography`
+/// package, over the EIP-191 prehashes of the messages below, with the private key
+/// `0x0102..39`. Each is followed by the 57-byte public key, as in [`SIGNATURE_LENGTH`].
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use crate::Signature;
+    use alloy_primitives::{address, hex, Address};
+
+    /// The signer of all fixtures: the last 20 bytes of the SHA3-256 hash of its public key.
+    pub(crate) const SIGNER: Address = address!("403d5aa58a4024069cc3dc25836be937fd9309fc");
+
+    /// The message signed by [`CORE_SIG`] and [`ETHEREUM_SIG`].
+    pub(crate) const MESSAGE: &[u8] = b"hello world";
+
+    /// Signature over [`MESSAGE`] framed with [`EIP191_PREFIX`](crate::EIP191_PREFIX).
+    pub(crate) const CORE_SIG: [u8; 171] = hex!(
+        "327489c11900d532db00d8036b5f41c906889b0d9c5e281ea7b24f8cdfd06140660ffb760f1c295bbcadcd1f"
+        "534d32675effc8d40fc5d12e004a3e48468db03447aec2191ee411ba89bfdb5f5efaa1578a47e24086d65c34"
+        "6a5deab58120c230af74b81e51164780266e362df9df78930c00da918ba3e57fdca0326f46c7ec843ba8fcb0"
+        "d57fa15f2588a57bae9df558210351e7e15581b24459c0a7cde1e835582d717c0699ea72e8c900"
+    );
+
+    /// Signature over [`MESSAGE`] framed with
+    /// [`ETHEREUM_EIP191_PREFIX`](crate::ETHEREUM_EIP191_PREFIX).
+    pub(crate) const ETHEREUM_SIG: [u8; 171] = hex!(
+        "a8983dcbba70d4fd3c65178b24032db3eab3fde2f0b34d4b76cf94e5d1ca781573f5223794f725d86214c565"
+        "2294d6fb6fd457a0db378d9000520e7f6a957128cc98847ced589e7e4396b53503a1d0241d1b397922fa31f8"
+        "a961b6d6c9705fa00574a7bc6337fc9a39792343fef7a8501900da918ba3e57fdca0326f46c7ec843ba8fcb0"
+        "d57fa15f2588a57bae9df558210351e7e15581b24459c0a7cde1e835582d717c0699ea72e8c900"
+    );
+
+    /// Signature over `login_message("example.com", 1_700_000_000, &B256::repeat_byte(0x42))`
+    /// framed with [`EIP191_PREFIX`](crate::EIP191_PREFIX).
+    pub(crate) const LOGIN_SIG: [u8; 171] = hex!(
+        "2279a214e3bafe94c5715ef9a1fe7c15cf790af1a093b9374bdc76180275dd381e717c8fc1b4180c0da4c3cc"
+        "2909131ae9beec7cb1ee054900d60017807f564bcd711dbcaf512a8056281fe876c71e81dd469315111aadf9"
+        "805f0e18cb83a3935ea395e643d6430f35d8282d0c00d7721800da918ba3e57fdca0326f46c7ec843ba8fcb0"
+        "d57fa15f2588a57bae9df558210351e7e15581b24459c0a7cde1e835582d717c0699ea72e8c900"
+    );
+
+    /// Parses a fixture.
+    pub(crate) fn sig(bytes: [u8; 171]) -> Signature {
+        Signature::try_from(&bytes[..]).unwrap()
+    }
+
+    /// Parses a fixture with one bit of the ed448 signature flipped, so it no longer verifies.
+    pub(crate) fn corrupt(mut bytes: [u8; 171]) -> Signature {
+        bytes[0] ^= 1;
+        sig(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fixtures::*, *};
+    use alloc::{format, string::ToString, vec::Vec};
+
+    fn sig_bytes() -> Vec<u8> {
+        (0..SIGNATURE_LENGTH as u8).collect()
+    }
+
+    #[test]
+    fn detect_encoding() {
+        assert_eq!(SignatureEncoding::detect("0xabcd"), SignatureEncoding::Hex);
+        assert_eq!(SignatureEncoding::detect("0Xabcd"), SignatureEncoding::Hex);
+        assert_eq!(SignatureEncoding::detect("q83v"), SignatureEncoding::Base64);
+    }
+
+    #[test]
+    fn decode_hex_and_base64() {
+        let hex_sig = hex::encode_prefixed(CORE_SIG);
+        let b64_sig = base64::engine::general_purpose::STANDARD.encode(CORE_SIG);
+        let upper_sig = format!("0X{}", hex::encode_upper(CORE_SIG));
+
+        assert_eq!(
+            decode_signature(&hex_sig, SignatureEncoding::Hex).unwrap().as_bytes(),
+            CORE_SIG
+        );
+        assert_eq!(
+            decode_signature(&b64_sig, SignatureEncoding::Base64).unwrap().as_bytes(),
+            CORE_SIG
+        );
+        assert_eq!(SignatureEncoding::detect(&upper_sig), SignatureEncoding::Hex);
+        assert_eq!(
+            decode_signature(&upper_sig, SignatureEncoding::Hex).unwrap().as_bytes(),
+            CORE_SIG
+        );
+    }
+
+    #[test]
+    fn decode_malformed() {
+        let bytes = sig_bytes();
+        // Not hex.
+        assert!(decode_signature("0xzz", SignatureEncoding::Hex).is_err());
+        // Not base64.
+        assert!(decode_signature("not base64!", SignatureEncoding::Base64).is_err());
+        // Too short.
+        let short = hex::encode_prefixed(&bytes[..SIGNATURE_LENGTH - 1]);
+        assert!(decode_signature(&short, SignatureEncoding::Hex).is_err());
+        let short = base64::engine::general_purpose::STANDARD.encode(&bytes[..65]);
+        assert!(decode_signature(&short, SignatureEncoding::Base64).is_err());
+        // Empty.
+        assert!(verify_eip191_str(b"hello", "", Address::ZERO).is_err());
+        assert!(verify_eip191_str(b"hello", "0x", Address::ZERO).is_err());
+    }
+
+    #[test]
+    fn verify_str() {
+        let hex_sig = hex::encode_prefixed(CORE_SIG);
+        let b64_sig = base64::engine::general_purpose::STANDARD.encode(CORE_SIG);
+        for sig in [&hex_sig, &b64_sig] {
+            assert!(verify_eip191_str(MESSAGE, sig, SIGNER).unwrap());
+            assert!(!verify_eip191_str(MESSAGE, sig, Address::ZERO).unwrap());
+        }
+
+        let corrupted = hex::encode_prefixed(corrupt(CORE_SIG).as_bytes());
+        assert!(verify_eip191_str(MESSAGE, &corrupted, SIGNER).is_err());
+    }
+
+    #[test]
+    fn memo_skips_repeat_recovery() {
+        let sig = Signature::try_from(sig_bytes().as_slice()).unwrap();
+        let signer = Address::repeat_byte(0x11);
+        let mut memo = RecoveryMemo::new();
+        let mut calls = 0;
+        for _ in 0..3 {
+            let recovered = memo.recover_with(B256::ZERO, &sig, |_, _| {
+                calls += 1;
+                Ok(signer)
+            });
+            assert_eq!(recovered.ok(), Some(signer));
+        }
+        assert_eq!(calls, 1);
+        assert_eq!(memo.len(), 1);
+
+        // Failures are not memoized.
+        let failed = memo.recover_with(B256::repeat_byte(1), &sig, |_, _| {
+            Err(SignatureError::FromBytes("invalid"))
+        });
+        assert!(failed.is_err());
+        assert_eq!(memo.len(), 1);
+        assert_eq!(
+            memo.recover_memoized(B256::ZERO, &sig).ok(),
+            Some(signer),
+            "served from the memo"
+        );
+    }
+
+    #[test]
+    fn any_prefix_falls_back_in_order() {
+        let prefixes = [crate::EIP191_PREFIX, crate::ETHEREUM_EIP191_PREFIX];
+        let core = sig(CORE_SIG);
+        let ethereum = sig(ETHEREUM_SIG);
+
+        assert_eq!(recover_eip191_any_prefix(MESSAGE, &core, &prefixes), Some((SIGNER, 0)));
+        assert_eq!(recover_eip191_any_prefix(MESSAGE, &ethereum, &prefixes), Some((SIGNER, 1)));
+        assert_eq!(recover_eip191_any_prefix(MESSAGE, &ethereum, &prefixes[..1]), None);
+        assert_eq!(recover_eip191_any_prefix(MESSAGE, &corrupt(CORE_SIG), &prefixes), None);
+        assert_eq!(recover_eip191_any_prefix(MESSAGE, &core, &[]), None);
+    }
+
+    #[test]
+    fn login_envelope() {
+        let nonce = B256::repeat_byte(0x42);
+        let envelope = login_message("example.com", 1_700_000_000, &nonce);
+        assert_eq!(
+            envelope,
+            "Sign in to example.com at 1700000000, nonce \
+             0x4242424242424242424242424242424242424242424242424242424242424242"
+        );
+
+        let sig = sig(LOGIN_SIG);
+        assert_eq!(recover_login("example.com", 1_700_000_000, &nonce, &sig).unwrap(), SIGNER);
+        // Any change to the envelope breaks the signature.
+        assert!(recover_login("example.com", 1_700_000_001, &nonce, &sig).is_err());
+        assert!(recover_login("example.org", 1_700_000_000, &nonce, &sig).is_err());
+    }
+
+    #[test]
+    fn any_candidate() {
+        let fake_sig = |byte: u8| {
+            let mut bytes = sig_bytes();
+            bytes[0] = byte;
+            Signature::try_from(bytes.as_slice()).unwrap()
+        };
+        let signer = |byte: u8| Address::repeat_byte(byte);
+        // Signatures starting with 0xff are malformed, the others recover to `signer(byte)`.
+        let recover = |_: &B256, sig: &Signature| match sig.as_bytes()[0] {
+            0xff => Err(SignatureError::FromBytes("invalid")),
+            byte => Ok(signer(byte)),
+        };
+
+        let candidates = [
+            (fake_sig(0xff), signer(1)),
+            (fake_sig(2), signer(3)),
+            (fake_sig(4), signer(4)),
+            (fake_sig(5), signer(5)),
+        ];
+        assert_eq!(verify_any_with(&B256::ZERO, &candidates, recover), Some(signer(4)));
+        assert_eq!(verify_any_with(&B256::ZERO, &candidates[..2], recover), None);
+        assert_eq!(verify_any_with(&B256::ZERO, &[], recover), None);
+
+        // Only the valid signature by its paired address authorizes the message.
+        let candidates = [
+            (corrupt(CORE_SIG), SIGNER),
+            (sig(CORE_SIG), Address::ZERO),
+            (sig(ETHEREUM_SIG), SIGNER),
+            (sig(CORE_SIG), SIGNER),
+        ];
+        assert_eq!(verify_eip191_any(MESSAGE, &candidates), Some(SIGNER));
+        assert_eq!(verify_eip191_any(MESSAGE, &candidates[..3]), None);
+    }
+
+    #[test]
+    fn streaming_verifier() {
+        let sig = sig(CORE_SIG);
+        let len = MESSAGE.len() as u64;
+
+        for chunk_size in [1, 3, MESSAGE.len()] {
+            let mut verifier = StreamingVerifier::new(len);
+            for chunk in MESSAGE.chunks(chunk_size) {
+                verifier.update(chunk);
+            }
+            assert_eq!(verifier.remaining(), 0);
+            assert!(verifier.clone().finalize_verify(&sig, SIGNER));
+            assert!(!verifier.clone().finalize_verify(&sig, Address::ZERO));
+            assert!(!verifier.finalize_verify(&corrupt(CORE_SIG), SIGNER));
+        }
+
+        // A truncated payload never verifies.
+        let mut verifier = StreamingVerifier::new(len);
+        verifier.update(&MESSAGE[..5]);
+        assert_eq!(verifier.remaining(), len - 5);
+        assert!(!verifier.finalize_verify(&sig, SIGNER));
+
+        // Declaring `1` and streaming `"1" || MESSAGE` absorbs the framing of the signed 11-byte
+        // `MESSAGE`, but the stream is not what was declared.
+        let mut verifier = StreamingVerifier::new(1);
+        verifier.update(b"1");
+        verifier.update(MESSAGE);
+        assert!(!verifier.finalize_verify(&sig, SIGNER));
+
+        // An extra byte after the signed payload.
+        let mut verifier = StreamingVerifier::new(len);
+        verifier.update(MESSAGE);
+        verifier.update(b"!");
+        assert_eq!(verifier.remaining(), 0);
+        assert!(!verifier.finalize_verify(&sig, SIGNER));
+    }
+
+    #[test]
+    fn recover_and_verify_failures() {
+        let sig = sig(CORE_SIG);
+        assert_eq!(recover_and_verify_eip191(MESSAGE, &sig, SIGNER).unwrap(), SIGNER);
+        match recover_and_verify_eip191(MESSAGE, &sig, Address::ZERO) {
+            Err(VerificationError::WrongSigner { expected, recovered }) => {
+                assert_eq!(expected, Address::ZERO);
+                assert_eq!(recovered, SIGNER);
+            }
+            res => panic!("expected a wrong signer error, got {res:?}"),
+        }
+        assert!(matches!(
+            recover_and_verify_eip191(MESSAGE, &corrupt(CORE_SIG), SIGNER),
+            Err(VerificationError::Signature(_))
+        ));
+
+        let err = VerificationError::WrongSigner {
+            expected: Address::ZERO,
+            recovered: Address::repeat_byte(0x11),
+        };
+        assert!(err.to_string().starts_with("wrong signer: expected 0x0000"));
+        assert!(core::error::Error::source(&err).is_none());
+        let err = VerificationError::from(SignatureError::FromBytes("invalid"));
+        assert!(core::error::Error::source(&err).is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn verify_reader() {
+        let sig = sig(CORE_SIG);
+        let len = MESSAGE.len() as u64;
+
+        let mut reader = MESSAGE;
+        assert!(verify_eip191_reader(&mut reader, len, &sig, SIGNER).unwrap());
+        let mut reader = MESSAGE;
+        assert!(!verify_eip191_reader(&mut reader, len, &sig, Address::ZERO).unwrap());
+
+        // Streaming in several chunks gives the same result as verifying the buffered message.
+        let (head, tail) = MESSAGE.split_at(4);
+        let (mid, tail) = tail.split_at(3);
+        for expected in [SIGNER, Address::ZERO] {
+            let mut reader = head.chain(mid).chain(tail);
+            assert_eq!(
+                verify_eip191_reader(&mut reader, len, &sig, expected).unwrap(),
+                verify_eip191(MESSAGE, &sig, expected).unwrap()
+            );
+        }
+
+        let mut reader = MESSAGE;
+        let err = verify_eip191_reader(&mut reader, len, &corrupt(CORE_SIG), SIGNER).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn verify_reader_short_input() {
+        let sig = sig(CORE_SIG);
+        let mut reader: &[u8] = b"hello";
+        let err = verify_eip191_reader(&mut reader, 6, &sig, SIGNER).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+        // Only the declared length is consumed.
+        let mut reader: &[u8] = b"hello world, and more";
+        assert!(verify_eip191_reader(&mut reader, MESSAGE.len() as u64, &sig, SIGNER).unwrap());
+        assert_eq!(reader, b", and more");
+    }
+}
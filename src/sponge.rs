@@ -0,0 +1,171 @@
+use core::fmt;
+
+/// Size of the Keccak-f\[1600\] state in bytes.
+const STATE_BYTES: usize = 200;
+
+/// A Keccak sponge with a configurable rate and domain-separation byte.
+///
+/// This is a low-level escape hatch for protocols with non-standard Keccak parameters. Use
+/// [`Sha3`](crate::Sha3), [`keccak256`](crate::keccak256) or [`Shake`](crate::Shake) for the
+/// standard functions, e.g. SHA3-256 is `KeccakSponge::new(136, 0x06)`.
+///
+/// This API is unstable and may change in any release.
+#[derive(Clone)]
+pub struct KeccakSponge {
+    state: [u64; 25],
+    rate: usize,
+    delim: u8,
+    offset: usize,
+    squeezing: bool,
+}
+
+impl fmt::Debug for KeccakSponge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeccakSponge")
+            .field("rate", &self.rate)
+            .field("delim", &self.delim)
+            .field("squeezing", &self.squeezing)
+            .finish_non_exhaustive()
+    }
+}
+
+impl KeccakSponge {
+    /// Creates a new sponge absorbing `rate` bytes per permutation. `delim` holds the domain
+    /// separation bits and the first padding bit, e.g. `0x06` for SHA3 and `0x1f` for SHAKE.
+    ///
+    /// Panics if `rate` is 0 or not smaller than the 200-byte state.
+    #[track_caller]
+    pub fn new(rate: usize, delim: u8) -> Self {
+        assert!(0 < rate && rate < STATE_BYTES, "rate must be between 1 and 199 bytes, got {rate}");
+        Self { state: [0; 25], rate, delim, offset: 0, squeezing: false }
+    }
+
+    /// Creates a sponge from raw Keccak-f\[1600\] state words, positioned at the start of a block.
+    ///
+    /// `state[x + 5 * y]` is the lane at column `x` and row `y`. Byte `i` of the state, as absorbed
+    /// and squeezed, is byte `i % 8` of `state[i / 8]` in little-endian order, i.e. bits
+    /// `8 * (i % 8)..8 * (i % 8) + 8`. This is the layout used by the reference implementation,
+    /// so states can be exchanged with other implementations, e.g. to cross-check GPU results.
+    ///
+    /// Panics if `rate` is invalid, see [`new`](Self::new).
+    #[track_caller]
+    pub fn from_state_words(rate: usize, delim: u8, state: [u64; 25]) -> Self {
+        Self { state, ..Self::new(rate, delim) }
+    }
+
+    /// Returns the raw state words, in the layout of [`from_state_words`](Self::from_state_words).
+    ///
+    /// Input absorbed since the last permutation is already XORed into the state.
+    #[inline]
+    pub fn state_words(&self) -> [u64; 25] {
+        self.state
+    }
+
+    /// Returns the rate in bytes.
+    #[inline]
+    pub fn rate(&self) -> usize {
+        self.rate
+    }
+
+    /// Absorbs additional input. Can be called multiple times, but not after squeezing.
+    ///
+    /// Panics if output was already squeezed.
+    #[track_caller]
+    pub fn absorb(&mut self, input: &[u8]) {
+        assert!(!self.squeezing, "cannot absorb input after squeezing");
+        for &byte in input {
+            self.xor_byte(self.offset, byte);
+            self.offset += 1;
+            if self.offset == self.rate {
+                tiny_keccak::keccakf(&mut self.state);
+                self.offset = 0;
+            }
+        }
+    }
+
+    /// Fills `output` with the next bytes of the output stream, padding the input first if this
+    /// is the first squeeze.
+    pub fn squeeze(&mut self, output: &mut [u8]) {
+        if !self.squeezing {
+            self.pad();
+        }
+        for byte in output {
+            if self.offset == self.rate {
+                tiny_keccak::keccakf(&mut self.state);
+                self.offset = 0;
+            }
+            *byte = (self.state[self.offset / 8] >> (8 * (self.offset % 8))) as u8;
+            self.offset += 1;
+        }
+    }
+
+    fn pad(&mut self) {
+        self.xor_byte(self.offset, self.delim);
+        self.xor_byte(self.rate - 1, 0x80);
+        tiny_keccak::keccakf(&mut self.state);
+        self.offset = 0;
+        self.squeezing = true;
+    }
+
+    #[inline]
+    fn xor_byte(&mut self, i: usize, byte: u8) {
+        self.state[i / 8] ^= u64::from(byte) << (8 * (i % 8));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keccak256, sha3, Shake};
+    use alloc::vec::Vec;
+
+    fn squeeze<const N: usize>(rate: usize, delim: u8, input: &[u8]) -> [u8; N] {
+        let mut sponge = KeccakSponge::new(rate, delim);
+        for chunk in input.chunks(50) {
+            sponge.absorb(chunk);
+        }
+        let mut out = [0u8; N];
+        sponge.squeeze(&mut out);
+        out
+    }
+
+    #[test]
+    fn standard_parameters() {
+        let long: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        for input in [&b""[..], b"hello world", &long[..135], &long[..136], &long] {
+            assert_eq!(squeeze::<32>(136, 0x06, input), sha3(input).0);
+            assert_eq!(squeeze::<32>(136, 0x01, input), keccak256(input).0);
+
+            let mut shake = Shake::new();
+            shake.update(input);
+            let mut expected = [0u8; 300];
+            shake.squeeze(&mut expected);
+            assert_eq!(squeeze::<300>(136, 0x1f, input), expected);
+        }
+    }
+
+    #[test]
+    fn state_words_layout() {
+        let mut sponge = KeccakSponge::new(136, 0x06);
+        sponge.absorb(&[0x01, 0x02]);
+        assert_eq!(sponge.state_words()[0], 0x0201);
+
+        // Absorbing a full block of zeros permutes the all-zero state once.
+        let mut sponge = KeccakSponge::new(136, 0x06);
+        sponge.absorb(&[0; 136]);
+        let state = sponge.state_words();
+        assert_eq!(state[0], 0xf1258f7940e1dde7);
+
+        let mut resumed = KeccakSponge::from_state_words(136, 0x06, state);
+        resumed.absorb(b"tail");
+        let mut out = [0u8; 32];
+        resumed.squeeze(&mut out);
+        assert_eq!(out, sha3([&[0u8; 136][..], b"tail"].concat()).0);
+    }
+
+    #[test]
+    #[should_panic = "rate must be between 1 and 199 bytes, got 200"]
+    fn rate_too_large() {
+        KeccakSponge::new(200, 0x06);
+    }
+}
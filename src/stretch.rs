@@ -0,0 +1,50 @@
+use crate::{sha3, Sha3};
+use alloy_primitives::B256;
+
+/// Derives a digest from `input` that takes `iterations` extra hashes to compute.
+///
+/// The state is seeded with `sha3(salt || input)` and then re-hashed `iterations` times, so
+/// `iterations = 0` is a single salted hash. The cost grows linearly with `iterations`.
+///
+/// # Security
+///
+/// This is iteration-based key stretching only. It needs no memory, so it is cheap to brute force
+/// in parallel on GPUs and ASICs, and it is NOT a substitute for a memory-hard password hash such
+/// as Argon2 or scrypt. Use it for low-stakes derivations, e.g. of internal tokens, and never to
+/// store user passwords.
+pub fn slow_hash(input: &[u8], salt: &B256, iterations: u32) -> B256 {
+    let mut hasher = Sha3::new();
+    hasher.update(salt);
+    hasher.update(input);
+    let mut digest = hasher.finalize();
+    for _ in 0..iterations {
+        digest = sha3(digest);
+    }
+    digest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::b256;
+
+    #[test]
+    fn slow_hash_vectors() {
+        let salt = B256::repeat_byte(0x42);
+        assert_eq!(slow_hash(b"token", &salt, 0), sha3([salt.as_slice(), b"token"].concat()));
+        assert_eq!(slow_hash(b"token", &salt, 1), sha3(slow_hash(b"token", &salt, 0)));
+        // Computed with Python's `hashlib.sha3_256`.
+        assert_eq!(
+            slow_hash(b"token", &salt, 1000),
+            b256!("a43f4114a100618b4c6ca9ebe8d82096366a737ff2bb6404873ead86f6a9adde")
+        );
+    }
+
+    #[test]
+    fn salt_and_iterations_matter() {
+        let a = slow_hash(b"token", &B256::repeat_byte(1), 100);
+        assert_eq!(a, slow_hash(b"token", &B256::repeat_byte(1), 100));
+        assert_ne!(a, slow_hash(b"token", &B256::repeat_byte(2), 100));
+        assert_ne!(a, slow_hash(b"token", &B256::repeat_byte(1), 101));
+    }
+}
@@ -0,0 +1,115 @@
+//! Test utilities: [`proptest`] strategies for messages and digests, and collision statistics
+//! for truncated digests.
+//!
+//! Downstream crates can use the strategies, behind the `proptest` feature, to property test
+//! against the invariants of this crate.
+
+use crate::sha3;
+use alloc::vec::Vec;
+#[cfg(feature = "proptest")]
+use crate::eip191_message;
+#[cfg(feature = "proptest")]
+use alloy_primitives::B256;
+#[cfg(feature = "proptest")]
+use proptest::{collection::vec, prelude::*};
+
+/// Collisions observed by [`collision_stats`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CollisionStats {
+    /// The number of hashed inputs.
+    pub items: usize,
+    /// The length of the compared digest prefixes in bytes.
+    pub prefix_bytes: usize,
+    /// The number of inputs whose prefix equals the prefix of another input, not counting the
+    /// first input of each group of equal prefixes.
+    pub collisions: usize,
+    /// The expected number of colliding pairs for a random function, `n (n - 1) / 2 / 2^(8k)`.
+    /// For small values, this approximates the expected value of `collisions`.
+    pub expected: f64,
+}
+
+/// Hashes the `n_items` inputs `0u64..n_items` in big-endian encoding, truncates the digests to
+/// `prefix_bytes` bytes and counts the colliding prefixes.
+///
+/// Use this to pick a length for short IDs, e.g. with [`sha3_prefix`](crate::sha3_prefix): the
+/// observed collisions should be close to `expected`, and zero for a safe length. The prefixes
+/// are sorted to find the collisions, so this takes `O(n log n)` time and `O(n)` memory.
+///
+/// Panics if `prefix_bytes` is greater than 32.
+#[track_caller]
+pub fn collision_stats(n_items: usize, prefix_bytes: usize) -> CollisionStats {
+    assert!(prefix_bytes <= 32, "prefix length {prefix_bytes} exceeds the 32-byte digest");
+    let mut prefixes: Vec<[u8; 32]> = (0..n_items as u64)
+        .map(|i| {
+            let mut prefix = [0u8; 32];
+            prefix[..prefix_bytes].copy_from_slice(&sha3(i.to_be_bytes())[..prefix_bytes]);
+            prefix
+        })
+        .collect();
+    prefixes.sort_unstable();
+    let collisions = prefixes.windows(2).filter(|w| w[0] == w[1]).count();
+
+    let n = n_items as f64;
+    let expected = (0..prefix_bytes).fold(n * (n - 1.0) / 2.0, |acc, _| acc / 256.0);
+    CollisionStats { items: n_items, prefix_bytes, collisions, expected }
+}
+
+/// Maximum length of the messages generated by [`arb_message`].
+#[cfg(feature = "proptest")]
+pub const MAX_MESSAGE_LEN: usize = 1024;
+
+/// Generates arbitrary messages of up to [`MAX_MESSAGE_LEN`] bytes.
+#[cfg(feature = "proptest")]
+pub fn arb_message() -> impl Strategy<Value = Vec<u8>> {
+    vec(any::<u8>(), 0..=MAX_MESSAGE_LEN)
+}
+
+/// Generates digests by hashing arbitrary messages.
+#[cfg(feature = "proptest")]
+pub fn arb_digest() -> impl Strategy<Value = B256> {
+    arb_message().prop_map(sha3)
+}
+
+/// Generates `(message, framed, hash)` triples, where `framed` is the [EIP-191] framing of
+/// `message` and `hash` is the SHA3-256 digest of `framed`.
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+#[cfg(feature = "proptest")]
+pub fn arb_eip191_roundtrip() -> impl Strategy<Value = (Vec<u8>, Vec<u8>, B256)> {
+    arb_message().prop_map(|message| {
+        let framed = eip191_message(&message);
+        let hash = sha3(&framed);
+        (message, framed, hash)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collisions() {
+        let stats = collision_stats(1000, 8);
+        assert_eq!(stats.collisions, 0);
+        assert!(stats.expected < 1e-10);
+
+        // 1000 inputs cannot have more than 256 distinct one-byte prefixes.
+        let stats = collision_stats(1000, 1);
+        assert!(stats.collisions >= 1000 - 256);
+        assert_eq!(collision_stats(1000, 1), stats);
+
+        assert_eq!(collision_stats(10, 0).collisions, 9);
+        assert_eq!(collision_stats(0, 4).collisions, 0);
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest! {
+        #[test]
+        fn eip191_consistency((message, framed, hash) in arb_eip191_roundtrip()) {
+            use crate::{eip191_hash_message, parse_eip191};
+
+            prop_assert_eq!(eip191_hash_message(&message), hash);
+            prop_assert_eq!(parse_eip191(&framed), Ok(&message[..]));
+        }
+    }
+}
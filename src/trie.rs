@@ -0,0 +1,190 @@
+//! Merkle-Patricia trie node hashing and proof verification with SHA3-256.
+//!
+//! Core uses the Ethereum trie layout, but nodes are referenced by their SHA3-256 hash instead of
+//! their Keccak-256 hash.
+
+use crate::{constants::SHA3_EMPTY, sha3};
+use alloc::vec::Vec;
+use alloy_primitives::{b256, B256};
+use alloy_rlp::{Header, EMPTY_STRING_CODE};
+
+/// Root hash of an empty trie: the SHA3-256 digest of the RLP encoding of the empty string.
+pub const EMPTY_ROOT_HASH: B256 =
+    b256!("bc2071a4de846f285702447f2589dd163678e0972a8a1b0d28b04ed5c094547f");
+
+/// Returns the hash of the RLP-encoded trie node `rlp_encoded`, or [`SHA3_EMPTY`] for empty input.
+#[inline]
+pub fn hash_node(rlp_encoded: &[u8]) -> B256 {
+    if rlp_encoded.is_empty() {
+        return SHA3_EMPTY;
+    }
+    sha3(rlp_encoded)
+}
+
+/// Verifies a Merkle-Patricia proof that `key` maps to `expected_value` in the trie with root
+/// `root`, or that `key` is absent if `expected_value` is `None`.
+///
+/// `proof` holds the RLP-encoded nodes on the path from the root, as returned by `eth_getProof`.
+/// Nodes shorter than 32 bytes are embedded in their parent and must not be repeated in the proof.
+/// `key` is the path in the trie; for the secure state and storage tries, that is the
+/// [`sha3`] of the address or storage slot. Values are compared as the raw bytes stored in the
+/// trie, e.g. the RLP encoding of an account.
+///
+/// Returns `false` for malformed nodes, hash mismatches and proofs with trailing nodes.
+pub fn verify_proof(
+    root: B256,
+    key: &[u8],
+    proof: &[&[u8]],
+    expected_value: Option<&[u8]>,
+) -> bool {
+    walk_proof(root, key, proof).is_some_and(|value| value == expected_value)
+}
+
+/// Walks `proof` along `key`, returning the proven value, `Some(None)` if the proof shows that the
+/// key is absent, or `None` if the proof is invalid.
+fn walk_proof<'a>(root: B256, key: &[u8], proof: &[&'a [u8]]) -> Option<Option<&'a [u8]>> {
+    let nibbles: Vec<u8> = key.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect();
+    let mut key = &nibbles[..];
+    let mut proof = proof.iter();
+    let mut node: &'a [u8] = match proof.next() {
+        Some(&node) if hash_node(node) == root => node,
+        None if root == EMPTY_ROOT_HASH => return Some(None),
+        _ => return None,
+    };
+
+    let value = loop {
+        let items = decode_list(node)?;
+        let child = match items[..] {
+            [ref children @ .., value] if children.len() == 16 => {
+                let Some((&nibble, rest)) = key.split_first() else {
+                    break Some(decode_string(value)?).filter(|value| !value.is_empty());
+                };
+                key = rest;
+                children[usize::from(nibble)]
+            }
+            [path, child] => {
+                let (is_leaf, path) = decode_path(decode_string(path)?)?;
+                if is_leaf {
+                    break if key == path { Some(decode_string(child)?) } else { None };
+                }
+                let Some(rest) = key.strip_prefix(&path[..]) else { break None };
+                key = rest;
+                child
+            }
+            _ => return None,
+        };
+
+        node = match child {
+            [EMPTY_STRING_CODE] => break None,
+            // Nodes shorter than 32 bytes are embedded as a list.
+            [0xc0..=0xff, ..] => child,
+            _ => {
+                let next = *proof.next()?;
+                if decode_string(child)? != hash_node(next).as_slice() {
+                    return None;
+                }
+                next
+            }
+        };
+    };
+    proof.next().is_none().then_some(value)
+}
+
+/// Splits the RLP list `node` into the raw encodings of its items.
+fn decode_list(node: &[u8]) -> Option<Vec<&[u8]>> {
+    let mut buf = node;
+    let header = Header::decode(&mut buf).ok()?;
+    if !header.list || buf.len() != header.payload_length {
+        return None;
+    }
+    let mut items = Vec::with_capacity(17);
+    while !buf.is_empty() {
+        let item = buf;
+        let header = Header::decode(&mut buf).ok()?;
+        let len = item.len() - buf.len() + header.payload_length;
+        items.push(item.get(..len)?);
+        buf = &item[len..];
+    }
+    Some(items)
+}
+
+/// Returns the payload of the RLP string `item`.
+fn decode_string(item: &[u8]) -> Option<&[u8]> {
+    let mut buf = item;
+    let header = Header::decode(&mut buf).ok()?;
+    (!header.list && buf.len() == header.payload_length).then_some(buf)
+}
+
+/// Decodes a hex-prefix encoded path into whether it belongs to a leaf and its nibbles.
+fn decode_path(encoded: &[u8]) -> Option<(bool, Vec<u8>)> {
+    let (&first, rest) = encoded.split_first()?;
+    let flag = first >> 4;
+    if flag > 3 || (flag & 1 == 0 && first & 0x0f != 0) {
+        return None;
+    }
+    let mut nibbles = Vec::with_capacity(rest.len() * 2 + 1);
+    if flag & 1 == 1 {
+        nibbles.push(first & 0x0f);
+    }
+    nibbles.extend(rest.iter().flat_map(|b| [b >> 4, b & 0x0f]));
+    Some((flag & 2 == 2, nibbles))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+
+    // The trie {do: verb, dog: puppy, doge: coin, horse: stallion}, built with a reference
+    // implementation in Python using `hashlib.sha3_256`. It has an extension at the root, a branch
+    // holding a value, and embedded leaf and branch nodes.
+    const ROOT: B256 = b256!("422a17a872ce2e062b0998b85bee742dfa98f429c49bb22b81495d23fd3ce841");
+    const NODES: [&[u8]; 4] = [
+        &hex!("e216a0568e6db2d47f9e5c5f30dc2d5fcb6d9dafce38a5990e30bf362315d810381f28"),
+        &hex!("f84080808080a0875103fd2801eac79545b2739cab2c633344468fd055df69ee4dc535f523beb2808080cf85206f727365887374616c6c696f6e8080808080808080"),
+        &hex!("e482006fa0062a6edc9128cd0a15c932cfb7e0755edf79f396c5c741c2a4cbf7c877e0ec36"),
+        &hex!("f3808080808080de17dc808080808080c63584636f696e8080808080808080808570757070798080808080808080808476657262"),
+    ];
+
+    #[test]
+    fn inclusion() {
+        assert!(verify_proof(ROOT, b"do", &NODES, Some(b"verb")));
+        assert!(verify_proof(ROOT, b"dog", &NODES, Some(b"puppy")));
+        assert!(verify_proof(ROOT, b"doge", &NODES, Some(b"coin")));
+        assert!(verify_proof(ROOT, b"horse", &NODES[..2], Some(b"stallion")));
+
+        assert!(!verify_proof(ROOT, b"dog", &NODES, Some(b"kitty")));
+        assert!(!verify_proof(ROOT, b"dog", &NODES, None));
+        assert!(!verify_proof(B256::ZERO, b"dog", &NODES, Some(b"puppy")));
+        // Trailing and missing nodes.
+        assert!(!verify_proof(ROOT, b"horse", &NODES, Some(b"stallion")));
+        assert!(!verify_proof(ROOT, b"dog", &NODES[..3], Some(b"puppy")));
+    }
+
+    #[test]
+    fn exclusion() {
+        assert!(verify_proof(ROOT, b"dot", &NODES, None));
+        assert!(verify_proof(ROOT, b"cat", &NODES[..2], None));
+        assert!(verify_proof(ROOT, b"hors", &NODES[..2], None));
+        assert!(!verify_proof(ROOT, b"cat", &NODES[..2], Some(b"meow")));
+    }
+
+    #[test]
+    fn tampered_node() {
+        let mut node = NODES[2].to_vec();
+        *node.last_mut().unwrap() ^= 1;
+        let proof = [NODES[0], NODES[1], &node, NODES[3]];
+        assert!(!verify_proof(ROOT, b"dog", &proof, Some(b"puppy")));
+    }
+
+    #[test]
+    fn empty_trie() {
+        assert_eq!(EMPTY_ROOT_HASH, sha3([EMPTY_STRING_CODE]));
+        assert!(verify_proof(EMPTY_ROOT_HASH, b"dog", &[], None));
+        assert!(!verify_proof(EMPTY_ROOT_HASH, b"dog", &[], Some(b"puppy")));
+        assert!(!verify_proof(ROOT, b"dog", &[], None));
+
+        assert_eq!(hash_node(&[]), SHA3_EMPTY);
+        assert_eq!(hash_node(NODES[0]), ROOT);
+    }
+}
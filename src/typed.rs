@@ -0,0 +1,45 @@
+use crate::sha3_json;
+use alloy_primitives::B256;
+use serde::Serialize;
+
+/// Serializes `value` canonically and returns the SHA3-256 digest of the encoding.
+///
+/// The canonical format is compact JSON with sorted object keys: `value` is first converted to a
+/// [`serde_json::Value`] and then hashed with [`sha3_json`]. The digest is therefore independent of
+/// the iteration order of maps such as `HashMap`, and the same logical value always hashes the
+/// same. Struct fields are encoded by name, so reordering fields in the definition does not change
+/// the digest, but renaming them does.
+///
+/// Fails if `value` cannot be represented as JSON, e.g. a map with non-string keys.
+pub fn hash_typed<T: Serialize + ?Sized>(value: &T) -> Result<B256, serde_json::Error> {
+    Ok(sha3_json(&serde_json::to_value(value)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha3;
+    use alloc::collections::BTreeMap;
+    use serde_json::json;
+
+    #[test]
+    fn canonical_encoding() {
+        let value = json!({ "b": [1, 2], "a": { "y": null, "x": "z" } });
+        assert_eq!(hash_typed(&value).unwrap(), sha3(br#"{"a":{"x":"z","y":null},"b":[1,2]}"#));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn independent_of_construction_order() {
+        let mut a = std::collections::HashMap::new();
+        let mut b = std::collections::HashMap::new();
+        for i in 0..100u32 {
+            a.insert(i.to_string(), i);
+            b.insert((99 - i).to_string(), 99 - i);
+        }
+        let expected = hash_typed(&a).unwrap();
+        assert_eq!(hash_typed(&b).unwrap(), expected);
+        assert_eq!(hash_typed(&a.into_iter().collect::<BTreeMap<_, _>>()).unwrap(), expected);
+        assert_ne!(hash_typed(&json!({ "0": 1 })).unwrap(), expected);
+    }
+}